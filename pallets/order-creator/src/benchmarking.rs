@@ -23,22 +23,101 @@ use frame_benchmarking::v2::*;
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
-use frame_support::assert_ok;
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_support::{assert_ok, traits::Get, BoundedBTreeMap, BoundedVec};
+use frame_system::RawOrigin;
 use pallet_broker::ConfigRecord;
+use scale_info::prelude::{vec, vec::Vec};
+use sp_trie::StorageProof;
+
+fn setup_profile<T: Config>() -> Result<ProfileName, BenchmarkError> {
+	let name = ProfileName::truncate_from(b"main".to_vec());
+	let profile = RequirementProfile {
+		requirements: GenericRequirements::new(28800),
+		cadence: 1,
+		active: true,
+	};
+	let mut profiles = RequirementProfiles::<T>::get();
+	profiles
+		.try_insert(name.clone(), ProfileRecord { profile, next_order: 0 })
+		.map_err(|_| BenchmarkError::Weightless)?;
+	RequirementProfiles::<T>::put(profiles);
+	Ok(name)
+}
+
+/// Fills up the requirement profiles, of which only as many are active as needed for making
+/// `orders` orders. Each active profile is split into as many orders as possible.
+fn setup_profiles<T: Config>(orders: u32) -> Result<Vec<ProfileName>, BenchmarkError> {
+	let max_split: u32 = T::MaxRegionSplit::get();
+	let mut profiles = BoundedBTreeMap::new();
+	for i in 0..T::MaxProfiles::get() {
+		let split = orders.saturating_sub(i.saturating_mul(max_split)).min(max_split);
+		let mut requirements = GenericRequirements::new(28800);
+		requirements.split = split.max(1);
+		let profile = RequirementProfile { requirements, cadence: 1, active: split > 0 };
+		profiles
+			.try_insert(
+				ProfileName::truncate_from(i.to_be_bytes().to_vec()),
+				ProfileRecord { profile, next_order: 0 },
+			)
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}
+	let names = profiles.keys().cloned().collect();
+	RequirementProfiles::<T>::put(profiles);
+	Ok(names)
+}
+
+fn setup_configuration<T: Config>() {
+	Configuration::<T>::put(ConfigRecord {
+		advance_notice: 10u32.into(),
+		interlude_length: 7_200u32.into(),
+		leadin_length: 21_600u32.into(),
+		region_length: 1_260,
+		ideal_bulk_proportion: Default::default(),
+		limit_cores_offered: None,
+		renewal_bump: Default::default(),
+		contribution_timeout: 1_260,
+	});
+	NextOrder::<T>::put(crate::Pallet::<T>::current_timeslice());
+}
+
+fn setup_scheduled_changes<T: Config>(
+	profile: ProfileName,
+	count: u32,
+) -> Result<(), BenchmarkError> {
+	let changes: Vec<_> = (1..=count)
+		.map(|at| ScheduledChange {
+			profile: profile.clone(),
+			at,
+			requirements: GenericRequirements::new(28800),
+		})
+		.collect();
+	ScheduledChanges::<T>::put(
+		BoundedVec::try_from(changes).map_err(|_| BenchmarkError::Weightless)?,
+	);
+	Ok(())
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
-	use frame_support::traits::EnsureOrigin;
-	use sp_runtime::Perbill;
+	use codec::Encode;
+	use frame_support::{
+		traits::{
+			fungible::{Inspect, Mutate},
+			EnsureOrigin, Get, Hooks,
+		},
+		BoundedBTreeMap,
+	};
+	use scale_info::prelude::collections::BTreeMap;
+	use sp_runtime::{FixedU128, Perbill, Saturating};
+	use xcm::latest::{Error as XcmError, Response};
 
 	#[benchmark]
 	fn set_configuration() -> Result<(), BenchmarkError> {
 		let origin =
 			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
-		let configuration = ConfigRecord::<BlockNumberFor<T>, RCBlockNumberOf<T>> {
+		let configuration = ConfigRecordOf::<T> {
 			advance_notice: 10u32.into(),
 			interlude_length: 7_200u32.into(),
 			leadin_length: 21_600u32.into(),
@@ -70,15 +149,323 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn set_coretime_requirements() -> Result<(), BenchmarkError> {
+	fn set_requirement_profile() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let profile = RequirementProfile {
+			requirements: GenericRequirements::new(28800),
+			cadence: 1,
+			active: true,
+		};
+
+		// The worst case is adding a profile when there is room for only one more.
+		let existing = T::MaxProfiles::get().saturating_sub(1);
+		let profiles: BTreeMap<_, _> = (0..existing)
+			.map(|index| {
+				let record = ProfileRecord { profile: profile.clone(), next_order: 0 };
+				(ProfileName::truncate_from(index.encode()), record)
+			})
+			.collect();
+		RequirementProfiles::<T>::put(
+			BoundedBTreeMap::try_from(profiles).map_err(|_| BenchmarkError::Weightless)?,
+		);
+
+		let name = ProfileName::truncate_from(b"main".to_vec());
+		let profile = Some(profile);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name.clone(), profile.clone());
+
+		assert_last_event::<T>(Event::RequirementProfileSet { name, profile }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_requirements_change() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let profile = setup_profile::<T>()?;
+
+		// The worst case is adding a change when there is room for only one more.
+		let existing = T::MaxScheduledChanges::get().saturating_sub(1);
+		setup_scheduled_changes::<T>(profile.clone(), existing)?;
+
+		let at = Timeslice::MAX;
+		let requirements = GenericRequirements::new(43200);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, profile.clone(), at, requirements.clone());
+
+		assert_last_event::<T>(
+			Event::RequirementsChangeScheduled { profile, at, requirements }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_requirements_change() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let profile = setup_profile::<T>()?;
+
+		// The worst case is cancelling the last of the scheduled changes.
+		let existing = T::MaxScheduledChanges::get();
+		setup_scheduled_changes::<T>(profile.clone(), existing)?;
+		let at = existing;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, profile.clone(), at);
+
+		assert_last_event::<T>(Event::RequirementsChangeCancelled { profile, at }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fee_funding() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let funding =
+			Some(FeeFundingConfig { source: whitelisted_caller(), min_balance: 0u32.into() });
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, funding.clone());
+
+		assert_last_event::<T>(Event::FeeFundingSet { funding }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_order_call_weight() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call_weight = Weight::from_parts(200_000_000, 20_000);
+		let fee_multiplier = FixedU128::from_rational(3, 2);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, call_weight, fee_multiplier);
+
+		assert_last_event::<T>(Event::OrderCallWeightSet { call_weight, fee_multiplier }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_order_status() -> Result<(), BenchmarkError> {
+		let origin = T::XcmResponseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// The worst case is a rejected order which gets retried.
+		let requirements =
+			OrderRequirements { begin: Timeslice::MAX, end: Timeslice::MAX, core_occupancy: 28800 };
+		let now = frame_system::Pallet::<T>::block_number();
+		let order_id = crate::Pallet::<T>::record_order(
+			now,
+			ProfileName::truncate_from(b"main".to_vec()),
+			requirements.clone(),
+		);
+		let retry =
+			RetryRecord { requirements, attempts: 1, next_attempt: now, deadline: Timeslice::MAX };
+		OrderQueries::<T>::insert(0, (order_id, retry));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, Response::ExecutionResult(Some((0, XcmError::Barrier))));
+
+		assert!(PendingRetries::<T>::get(order_id).is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn preview_order_messages(
+		n: Linear<1, { T::MaxProfiles::get().saturating_mul(T::MaxRegionSplit::get()) }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		setup_configuration::<T>();
+		// The messages of `n` orders are built, while all the profiles are iterated over.
+		setup_profiles::<T>(n)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller));
+
+		// Nothing gets recorded or sent.
+		assert_eq!(frame_system::Pallet::<T>::events().len(), n as usize);
+		assert_eq!(NextOrderId::<T>::get(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn pause() -> Result<(), BenchmarkError> {
 		let origin =
 			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
-		let requirements = Some(GenericRequirements { core_occupancy: 28800u16.into() });
+		let until = Some(Timeslice::MAX);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, until);
+
+		assert_last_event::<T>(Event::OrderCreationPaused { until }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		setup_configuration::<T>();
+		Paused::<T>::put(PauseRecord { until: None });
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(Paused::<T>::get().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_order(n: Linear<1, { T::MaxRegionSplit::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		setup_configuration::<T>();
+		let profile = setup_profile::<T>()?;
+		// The requirements of the profile are split into `n` orders.
+		RequirementProfiles::<T>::mutate(|profiles| {
+			if let Some(record) = profiles.get_mut(&profile) {
+				record.profile.requirements.split = n;
+			}
+		});
+
+		// The fee of each order is funded.
+		let source: T::AccountId = whitelisted_caller();
+		let fees = crate::Pallet::<T>::order_fee().saturating_mul(n.saturating_add(1).into());
+		T::RelaychainCurrency::set_balance(
+			&source,
+			T::RelaychainCurrency::minimum_balance().saturating_add(fees.into()),
+		);
+		FeeFunding::<T>::put(FeeFundingConfig { source, min_balance: 0u32.into() });
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, profile, None);
+
+		assert_eq!(NextOrderId::<T>::get(), n);
+		assert_eq!(
+			Orders::<T>::get(n - 1).map(|order| order.status),
+			Some(OrderStatus::Dispatched)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn sync_coretime_state(
+		n: Linear<0, { T::MaxStateProofSize::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (relay_proof, coretime_proof) = T::BenchmarkHelper::coretime_state_proofs();
+
+		// The proofs are padded with an unused node, so that their combined size is close to `n`.
+		// The length prefixes of the node and of the proof take up to eight bytes.
+		let padding = n
+			.saturating_sub(state_proofs_size(&relay_proof, &coretime_proof))
+			.saturating_sub(8);
+		let coretime_proof = if padding > 0 {
+			StorageProof::merge([coretime_proof, StorageProof::new([vec![0u8; padding as usize]])])
+		} else {
+			coretime_proof
+		};
 
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, requirements.clone());
+		_(RawOrigin::Signed(caller), relay_proof, coretime_proof);
+
+		assert!(Configuration::<T>::get().is_some());
+		assert!(NextOrder::<T>::get().is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_idle() -> Result<(), BenchmarkError> {
+		// The worst case without making any orders is when the orders are due, but all profiles
+		// are inactive. The schedule is advanced nonetheless and the due requirement changes are
+		// applied.
+		setup_configuration::<T>();
+		let profiles = setup_profiles::<T>(0)?;
+		let profile = profiles.first().cloned().ok_or(BenchmarkError::Weightless)?;
+		setup_scheduled_changes::<T>(profile, T::MaxScheduledChanges::get())?;
+		let next_order = NextOrder::<T>::get().unwrap_or_default();
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			crate::Pallet::<T>::on_initialize(now);
+		}
+
+		assert_eq!(NextOrder::<T>::get(), Some(next_order.saturating_add(1_260)));
+		assert_eq!(NextOrderId::<T>::get(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_order(
+		n: Linear<1, { T::MaxProfiles::get().saturating_mul(T::MaxRegionSplit::get()) }>,
+	) -> Result<(), BenchmarkError> {
+		setup_configuration::<T>();
+		// The `n` orders are made for as few profiles as possible, while the remaining profiles
+		// are iterated over as well.
+		let profiles = setup_profiles::<T>(n)?;
+		let profile = profiles.first().cloned().ok_or(BenchmarkError::Weightless)?;
+		let requirements = RequirementProfiles::<T>::get()
+			.get(&profile)
+			.map(|record| record.profile.requirements.clone())
+			.ok_or(BenchmarkError::Weightless)?;
+
+		// Half of the scheduled changes are applied, while the other half remains pending and is
+		// written back. The applied changes keep the requirements of the profile.
+		let max_changes = T::MaxScheduledChanges::get();
+		let pending = max_changes / 2;
+		setup_scheduled_changes::<T>(profile, max_changes)?;
+		ScheduledChanges::<T>::mutate(|changes| {
+			let due = changes.len().saturating_sub(pending as usize);
+			for (i, change) in changes.iter_mut().enumerate() {
+				change.requirements = requirements.clone();
+				if i >= due {
+					change.at = Timeslice::MAX;
+				}
+			}
+		});
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			crate::Pallet::<T>::on_initialize(now);
+		}
+
+		assert_eq!(NextOrderId::<T>::get(), n);
+		assert_eq!(ScheduledChanges::<T>::get().len(), pending as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn send_queued_order() -> Result<(), BenchmarkError> {
+		setup_configuration::<T>();
+		setup_profile::<T>()?;
+		let now = frame_system::Pallet::<T>::block_number();
+		crate::Pallet::<T>::make_orders(now);
+		let (order_id, order) =
+			QueuedOrders::<T>::drain().next().ok_or(BenchmarkError::Weightless)?;
+
+		// The fee of the order is funded.
+		let source: T::AccountId = whitelisted_caller();
+		let fees = crate::Pallet::<T>::order_fee().saturating_mul(2u32.into());
+		T::RelaychainCurrency::set_balance(
+			&source,
+			T::RelaychainCurrency::minimum_balance().saturating_add(fees.into()),
+		);
+		FeeFunding::<T>::put(FeeFundingConfig { source, min_balance: 0u32.into() });
+
+		#[block]
+		{
+			crate::Pallet::<T>::send_queued_order(now, order_id, order);
+		}
 
-		assert_last_event::<T>(Event::CoretimeRequirementSet { requirements }.into());
+		assert_eq!(
+			Orders::<T>::get(order_id).map(|order| order.status),
+			Some(OrderStatus::Dispatched)
+		);
 		Ok(())
 	}
 
@@ -1,60 +1,269 @@
-use crate::{types::CallEncoder, OrderRequirements, LOG_TARGET};
+use crate::{types::CallEncoder, Error, OrderRequirements, LOG_TARGET};
 use core::marker::PhantomData;
-use frame_support::weights::WeightToFee;
-use scale_info::prelude::vec;
-use sp_runtime::{traits::Get, DispatchResult};
-use xcm::latest::prelude::*;
+use frame_support::{dispatch::GetDispatchInfo, storage_alias, Blake2_128Concat};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use scale_info::prelude::{boxed::Box, vec};
+use sp_runtime::{
+	traits::{Get, Saturating},
+	DispatchResult,
+};
+use xcm::{latest::prelude::*, VersionedMultiAssets, VersionedMultiLocation};
+use xcm_executor::traits::WeightBounds;
+
+/// The default weight of the order creation call on the RegionX parachain.
+///
+/// `ref_time` = 53372000, we will round up to: 100000000.
+/// `proof_size` = 6156, we will round up to: 10000.
+pub const DEFAULT_ORDER_CALL_WEIGHT: Weight = Weight::from_parts(100_000_000, 10_000);
 
 /// Type able to dispatch coretime orders to the RegionX parachain.
 pub trait OrderDispatcher {
 	/// Constructs the order based on the requirements and dispatches it to the RegionX parachain.
-	fn dispatch(requirements: OrderRequirements) -> DispatchResult;
+	///
+	/// Returns the id of the query through which the outcome of the order will be reported, if
+	/// any. In case of a failure the reason for which the order couldn't be sent is returned.
+	fn dispatch(requirements: OrderRequirements) -> Result<Option<QueryId>, SendError>;
+
+	/// Builds the message `dispatch` would send for the order, together with its destination,
+	/// without sending it.
+	///
+	/// Building the message may modify the state, e.g. by registering the query for the outcome of
+	/// the order, so callers are expected to discard any changes.
+	fn order_message(
+		_requirements: OrderRequirements,
+	) -> Result<(MultiLocation, Xcm<()>), SendError> {
+		Err(SendError::NotApplicable)
+	}
+
+	/// The weight of the message `dispatch` sends for an order whose call weighs `call_weight`.
+	///
+	/// The fee of an order has to cover the execution of the whole message on the RegionX
+	/// parachain, not only of the call. Defaults to the weight of the call.
+	fn order_weight(call_weight: Weight) -> Weight {
+		call_weight
+	}
 }
 
+/// The queries registered with `pallet_xcm`, which doesn't provide a way of dropping a query
+/// whose message couldn't be sent.
+#[storage_alias]
+type Queries<T: pallet_xcm::Config> = StorageMap<
+	pallet_xcm::Pallet<T>,
+	Blake2_128Concat,
+	QueryId,
+	pallet_xcm::QueryStatus<BlockNumberFor<T>>,
+>;
+
+/// Sends the order to the RegionX parachain and registers a query with `pallet_xcm` through
+/// which RegionX reports back whether the order got created.
 pub struct DefaultOrderDispatcher<T: crate::Config + pallet_xcm::Config>(PhantomData<T>);
-impl<T: crate::Config + pallet_xcm::Config> OrderDispatcher for DefaultOrderDispatcher<T> {
-	fn dispatch(requirements: OrderRequirements) -> DispatchResult {
-		let call = T::CallEncoder::order_creation_call(requirements);
-
-		// `ref_time` = 53372000, we will round up to: 100000000.
-		// `proof_size` = 6156, we will round up to: 10000.
-		let call_weight = Weight::from_parts(100_000_000, 10_000);
-		let fee = T::WeightToFee::weight_to_fee(&call_weight);
-
-		let message = Xcm(vec![
-			Instruction::WithdrawAsset(
-				MultiAsset { id: Concrete(MultiLocation::parent()), fun: Fungible(fee.into()) }
-					.into(),
-			),
-			Instruction::BuyExecution {
-				fees: MultiAsset {
-					id: Concrete(MultiLocation::parent()),
-					fun: Fungible(fee.into()),
-				},
-				weight_limit: Unlimited,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::SovereignAccount,
-				require_weight_at_most: call_weight,
-				call: call.into(),
+impl<T: crate::Config + pallet_xcm::Config> OrderDispatcher for DefaultOrderDispatcher<T>
+where
+	<T as pallet_xcm::Config>::RuntimeCall: From<crate::Call<T>>,
+{
+	fn dispatch(requirements: OrderRequirements) -> Result<Option<QueryId>, SendError> {
+		let (destination, message, query_id) = Self::prepare(requirements)?;
+		if let Err(error) = pallet_xcm::Pallet::<T>::send_xcm(Here, destination, message) {
+			// No response will ever arrive.
+			Queries::<T>::remove(query_id);
+			return Err(error);
+		}
+
+		log::info!(
+			target: LOG_TARGET,
+			"Coretime order sent successfully"
+		);
+		Ok(Some(query_id))
+	}
+
+	fn order_message(
+		requirements: OrderRequirements,
+	) -> Result<(MultiLocation, Xcm<()>), SendError> {
+		let (destination, message, _) = Self::prepare(requirements)?;
+		Ok((destination, message))
+	}
+
+	/// Weighs the message with the `Weigher` of `pallet_xcm`, which should match the one of the
+	/// RegionX parachain.
+	fn order_weight(call_weight: Weight) -> Weight {
+		let destination = <T as crate::Config>::RegionXLocation::get();
+		let Ok(sovereign_account) = sovereign_account_on::<T>(&destination) else {
+			return call_weight;
+		};
+
+		// Neither the requirements nor the fee affect the weight of the message.
+		let requirements = OrderRequirements { begin: 0, end: 0, core_occupancy: 0 };
+		let mut message = order_message::<T>(
+			requirements,
+			0,
+			call_weight,
+			sovereign_account,
+			QueryResponseInfo {
+				destination: sovereign_account,
+				query_id: 0,
+				max_weight: Weight::zero(),
 			},
-		]);
-
-		match pallet_xcm::Pallet::<T>::send_xcm(
-			Here,
-			<T as crate::Config>::RegionXLocation::get(),
-			message,
-		) {
-			Ok(_) => log::info!(
-				target: LOG_TARGET,
-				"Coretime order sent successfully"
-			),
-			Err(e) => log::error!(
+		)
+		.into();
+		<T as pallet_xcm::Config>::Weigher::weight(&mut message).unwrap_or_else(|()| {
+			log::error!(
 				target: LOG_TARGET,
-				"Failed to send coretime order: {:?}",
-				e
-			),
-		}
-		Ok(())
+				"Failed to weigh the order message"
+			);
+			call_weight
+		})
+	}
+}
+
+impl<T: crate::Config + pallet_xcm::Config> DefaultOrderDispatcher<T>
+where
+	<T as pallet_xcm::Config>::RuntimeCall: From<crate::Call<T>>,
+{
+	/// Registers the query for the outcome of the order and builds the message which creates the
+	/// order on the RegionX parachain.
+	fn prepare(
+		requirements: OrderRequirements,
+	) -> Result<(MultiLocation, Xcm<()>, QueryId), SendError> {
+		let destination = <T as crate::Config>::RegionXLocation::get();
+		let sovereign_account =
+			sovereign_account_on::<T>(&destination).map_err(|()| SendError::NotApplicable)?;
+
+		let call_weight = crate::OrderCallWeight::<T>::get();
+		let fee = crate::Pallet::<T>::order_fee();
+
+		// Only the call index matters, the arguments are filled in by `pallet_xcm` once the
+		// response arrives.
+		let notify: <T as pallet_xcm::Config>::RuntimeCall =
+			crate::Call::<T>::notify_order_status {
+				query_id: Default::default(),
+				response: Default::default(),
+			}
+			.into();
+		let max_weight = notify.get_dispatch_info().weight;
+		let timeout = frame_system::Pallet::<T>::block_number()
+			.saturating_add(<T as crate::Config>::ResponseTimeout::get());
+		let query_id =
+			pallet_xcm::Pallet::<T>::new_notify_query(destination, notify, timeout, Here);
+
+		let message = order_message::<T>(
+			requirements,
+			fee.into(),
+			call_weight,
+			sovereign_account,
+			QueryResponseInfo { destination: sovereign_account, query_id, max_weight },
+		);
+
+		Ok((destination, message, query_id))
+	}
+}
+
+/// Returns the message creating an order on the RegionX parachain, paying `fee` for its
+/// execution from the sovereign account and reporting the outcome through `report`.
+fn order_message<T: crate::Config>(
+	requirements: OrderRequirements,
+	fee: u128,
+	call_weight: Weight,
+	sovereign_account: MultiLocation,
+	report: QueryResponseInfo,
+) -> Xcm<()> {
+	let fee_asset = MultiAsset { id: Concrete(MultiLocation::parent()), fun: Fungible(fee) };
+
+	Xcm(vec![
+		Instruction::WithdrawAsset(fee_asset.clone().into()),
+		Instruction::BuyExecution { fees: fee_asset, weight_limit: Unlimited },
+		// The delivery fee of the report is withdrawn from the sovereign account when the report
+		// is sent, instead of from the fees bought for the execution.
+		Instruction::SetFeesMode { jit_withdraw: true },
+		// Report the outcome of the execution back to us, and return the unused fees to the
+		// sovereign account, even if the `Transact` fails. Otherwise they would end up in the
+		// asset trap of the RegionX parachain.
+		//
+		// The report goes first, so that the deposit doesn't take the fees it might need.
+		Instruction::SetAppendix(Xcm(vec![
+			Instruction::ReportError(report),
+			Instruction::RefundSurplus,
+			Instruction::DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: sovereign_account,
+			},
+		])),
+		Instruction::Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most: call_weight,
+			call: T::CallEncoder::order_creation_call(requirements).into(),
+		},
+		// A failing call doesn't make the `Transact` itself fail, so we turn it into an
+		// error which gets reported.
+		Instruction::ExpectTransactStatus(MaybeErrorCode::Success),
+	])
+}
+
+/// Type able to move relay chain tokens to the parachain's sovereign account on the RegionX
+/// parachain, so that it can pay for the execution of the orders.
+pub trait FeeFunder<AccountId, Balance> {
+	/// Transfers `amount` from the `source` account to the sovereign account of the parachain on
+	/// the RegionX parachain.
+	fn fund(source: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+/// Funds the order fees by teleporting relay chain tokens to the RegionX parachain.
+///
+/// The teleport is sent straight to the RegionX parachain before the order, so the fee arrives
+/// ahead of the order paying for it. This is the only funder which keeps that ordering.
+pub struct TeleportFeeFunder<T: crate::Config + pallet_xcm::Config>(PhantomData<T>);
+impl<T: crate::Config + pallet_xcm::Config> FeeFunder<T::AccountId, T::RelaychainBalance>
+	for TeleportFeeFunder<T>
+{
+	fn fund(source: &T::AccountId, amount: T::RelaychainBalance) -> DispatchResult {
+		let (dest, beneficiary, assets) = fee_transfer::<T>(amount)?;
+		pallet_xcm::Pallet::<T>::limited_teleport_assets(
+			RawOrigin::Signed(source.clone()).into(),
+			Box::new(VersionedMultiLocation::from(dest)),
+			Box::new(VersionedMultiLocation::from(beneficiary)),
+			Box::new(VersionedMultiAssets::from(assets)),
+			0,
+			Unlimited,
+		)
+	}
+}
+
+/// Funds the order fees by reserve transferring relay chain tokens to the RegionX parachain.
+///
+/// The relay chain holds the reserve of the tokens, so they reach the RegionX parachain through
+/// it, a block or two after the order which is sent straight to RegionX. The order is therefore
+/// paid for from whatever the sovereign account already holds, and fails if that doesn't cover
+/// its fee. This funder only suits parachains keeping the sovereign account topped up ahead of
+/// the orders, e.g. by funding it beyond the fee of a single order.
+pub struct ReserveTransferFeeFunder<T: crate::Config + pallet_xcm::Config>(PhantomData<T>);
+impl<T: crate::Config + pallet_xcm::Config> FeeFunder<T::AccountId, T::RelaychainBalance>
+	for ReserveTransferFeeFunder<T>
+{
+	fn fund(source: &T::AccountId, amount: T::RelaychainBalance) -> DispatchResult {
+		let (dest, beneficiary, assets) = fee_transfer::<T>(amount)?;
+		pallet_xcm::Pallet::<T>::limited_reserve_transfer_assets(
+			RawOrigin::Signed(source.clone()).into(),
+			Box::new(VersionedMultiLocation::from(dest)),
+			Box::new(VersionedMultiLocation::from(beneficiary)),
+			Box::new(VersionedMultiAssets::from(assets)),
+			0,
+			Unlimited,
+		)
 	}
 }
+
+/// Returns the destination, beneficiary and assets of a fee transfer to the RegionX parachain.
+fn fee_transfer<T: crate::Config + pallet_xcm::Config>(
+	amount: T::RelaychainBalance,
+) -> Result<(MultiLocation, MultiLocation, MultiAssets), Error<T>> {
+	let dest = <T as crate::Config>::RegionXLocation::get();
+	let beneficiary = sovereign_account_on::<T>(&dest).map_err(|()| Error::<T>::Unreachable)?;
+	let assets: MultiAssets =
+		MultiAsset { id: Concrete(MultiLocation::parent()), fun: Fungible(amount.into()) }.into();
+
+	Ok((dest, beneficiary, assets))
+}
+
+/// Returns the location of our sovereign account as seen from `dest`.
+fn sovereign_account_on<T: pallet_xcm::Config>(dest: &MultiLocation) -> Result<MultiLocation, ()> {
+	<T as pallet_xcm::Config>::UniversalLocation::get().invert_target(dest)
+}
@@ -16,20 +16,29 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod xcm_mock;
+
+#[cfg(test)]
+mod xcm_tests;
+
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
 mod dispatcher;
 pub use crate::dispatcher::*;
 
-const LOG_TARGET: &str = "runtime::order-creator";
+mod state_proof;
+pub use crate::state_proof::*;
 
-pub trait WeightInfo {
-	fn set_configuration() -> Weight;
-	fn schedule_next_order() -> Weight;
-	fn set_coretime_requirements() -> Weight;
-	fn dispatch() -> Weight;
-}
+pub mod migration;
+
+pub mod runtime_api;
+
+pub mod weights;
+pub use crate::weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::order-creator";
 
 pub struct TestWeightInfo;
 impl WeightInfo for TestWeightInfo {
@@ -39,7 +48,46 @@ impl WeightInfo for TestWeightInfo {
 	fn schedule_next_order() -> Weight {
 		Default::default()
 	}
-	fn set_coretime_requirements() -> Weight {
+	fn set_requirement_profile() -> Weight {
+		Default::default()
+	}
+	fn set_fee_funding() -> Weight {
+		Default::default()
+	}
+	fn set_order_call_weight() -> Weight {
+		Default::default()
+	}
+	fn notify_order_status() -> Weight {
+		Default::default()
+	}
+	fn schedule_requirements_change() -> Weight {
+		Default::default()
+	}
+	fn cancel_requirements_change() -> Weight {
+		Default::default()
+	}
+	fn preview_order_messages(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn pause() -> Weight {
+		Default::default()
+	}
+	fn resume() -> Weight {
+		Default::default()
+	}
+	fn force_order(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn sync_coretime_state(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn on_initialize_idle() -> Weight {
+		Default::default()
+	}
+	fn on_initialize_order(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn send_queued_order() -> Weight {
 		Default::default()
 	}
 	fn dispatch() -> Weight {
@@ -47,6 +95,9 @@ impl WeightInfo for TestWeightInfo {
 	}
 }
 
+// The code generated for the calls returning `DispatchResultWithPostInfo` trips
+// `clippy::useless_conversion`.
+#[allow(clippy::useless_conversion)]
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -60,8 +111,17 @@ pub mod pallet {
 		weights::WeightToFee,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::BlockNumberProvider;
-	use xcm::opaque::lts::MultiLocation;
+	use scale_info::prelude::{boxed::Box, vec};
+	use sp_runtime::{
+		traits::{BlockNumberProvider, One, Saturating, Zero},
+		FixedPointNumber, FixedU128, Perbill, TransactionOutcome,
+	};
+	use sp_trie::StorageProof;
+	use xcm::{
+		latest::{Error as XcmError, QueryId, Response, SendError},
+		opaque::lts::MultiLocation,
+		VersionedXcm,
+	};
 
 	/// The module configuration trait.
 	#[pallet::config]
@@ -91,6 +151,46 @@ pub mod pallet {
 		/// Type responsible for dispatching coretime orders to the RegionX parachain.
 		type OrderDispatcher: OrderDispatcher;
 
+		/// The origin through which the outcome of the orders is reported back.
+		///
+		/// This should be the response origin of `pallet_xcm`. Only the responses coming from
+		/// `RegionXLocation` are accepted.
+		type XcmResponseOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Type responsible for topping up the parachain's sovereign account on the RegionX
+		/// parachain with the fees for the orders.
+		type FeeFunder: FeeFunder<Self::AccountId, Self::RelaychainBalance>;
+
+		/// Type reporting the recent usage of the parachain.
+		///
+		/// Used for deriving the core occupancy of requirements with occupancy bounds.
+		type UsageProvider: UsageProvider;
+
+		/// The id of the Coretime chain.
+		///
+		/// Used for verifying state proofs of the Coretime chain.
+		#[pallet::constant]
+		type CoretimeParaId: Get<ParaId>;
+
+		/// Type providing the relay chain state root against which the state proofs of the
+		/// Coretime chain are verified.
+		type RelayStateRootProvider: RelayStateRootProvider;
+
+		/// The types with which the state of the Coretime chain is laid out.
+		///
+		/// Its block number is also the one in which the lengths of the sale periods in the
+		/// `Configuration` are expressed.
+		type CoretimeChain: CoretimeChain;
+
+		/// The maximum combined size in bytes of the state proofs accepted by
+		/// `sync_coretime_state`.
+		#[pallet::constant]
+		type MaxStateProofSize: Get<u32>;
+
+		/// Type providing valid state proofs of the Coretime chain for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+
 		/// Type which will return the scale encoded call for creating an order.
 		type CallEncoder: CallEncoder;
 
@@ -101,11 +201,66 @@ pub mod pallet {
 		#[pallet::constant]
 		type TimeslicePeriod: Get<RCBlockNumberOf<Self>>;
 
+		/// Number of Relay-chain blocks per Coretime chain block.
+		///
+		/// The lengths of the sale periods in the `Configuration` are expressed in Coretime chain
+		/// blocks. This is used for converting them to timeslices.
+		#[pallet::constant]
+		type CoretimeBlockPeriod: Get<RCBlockNumberOf<Self>>;
+
+		/// The maximum number of blocks a due order waits for spare block weight before it is sent.
+		///
+		/// Orders are sent in `on_idle` whenever there is enough weight left in the block. Orders
+		/// which weren't sent within this many blocks are sent in `on_initialize` instead, so that
+		/// they reach the RegionX parachain well before their region begins.
+		#[pallet::constant]
+		type MaxDispatchDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of times a failed order dispatch will be retried.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// The number of blocks to wait before retrying a failed order dispatch.
+		///
+		/// The delay doubles with each failed attempt.
+		#[pallet::constant]
+		type RetryDelay: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of orders kept in the order history.
+		///
+		/// Once exceeded, the oldest orders get removed.
+		#[pallet::constant]
+		type MaxOrderHistory: Get<u32>;
+
+		/// The maximum number of consecutive orders into which the coretime required for a bulk
+		/// period can be split.
+		#[pallet::constant]
+		type MaxRegionSplit: Get<u32>;
+
+		/// The maximum number of requirement profiles.
+		#[pallet::constant]
+		type MaxProfiles: Get<u32>;
+
+		/// The maximum number of scheduled requirement changes.
+		#[pallet::constant]
+		type MaxScheduledChanges: Get<u32>;
+
+		/// The number of blocks to wait for RegionX to report the outcome of an order.
+		///
+		/// Orders whose outcome isn't reported in time are not retried, since they may have been
+		/// created.
+		#[pallet::constant]
+		type ResponseTimeout: Get<BlockNumberFor<Self>>;
+
 		/// Weight Info
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The current configuration of the Coretime chain.
@@ -116,8 +271,9 @@ pub mod pallet {
 	/// update the `Configuration` to match the new config on the Coretime chain. If not updated, we
 	/// run the risk of creating redundant orders or missing an order creation.
 	///
-	/// Ideally, we would read the current sale state directly from the Coretime chain. However,
-	/// that would require using something like ISMP and a relay infrastructure.
+	/// Instead of setting the `Configuration` by hand, the `AdminOrigin` can keep it in sync with
+	/// the Coretime chain by submitting state proofs of the Coretime chain through
+	/// `sync_coretime_state`.
 	#[pallet::storage]
 	#[pallet::getter(fn configuration)]
 	pub type Configuration<T: Config> = StorageValue<_, ConfigRecordOf<T>, OptionQuery>;
@@ -134,12 +290,185 @@ pub mod pallet {
 	#[pallet::getter(fn next_order)]
 	pub type NextOrder<T: Config> = StorageValue<_, Timeslice, OptionQuery>;
 
-	/// The coretime requirements for the parachain.
+	/// The coretime requirement profiles of the parachain.
+	///
+	/// Orders are made for each active profile based on its requirements and cadence.
+	#[pallet::storage]
+	#[pallet::getter(fn requirement_profiles)]
+	pub type RequirementProfiles<T: Config> =
+		StorageValue<_, BoundedBTreeMap<ProfileName, ProfileRecord, T::MaxProfiles>, ValueQuery>;
+
+	/// Changes to the requirement profiles which are yet to take effect.
+	///
+	/// Sorted by the timeslice from which the changes apply.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_changes)]
+	pub type ScheduledChanges<T: Config> =
+		StorageValue<_, BoundedVec<ScheduledChange, T::MaxScheduledChanges>, ValueQuery>;
+
+	/// Set while the order creation is paused.
+	///
+	/// While paused no orders are made and no failed orders are retried. The requirement
+	/// profiles are kept as they are.
+	#[pallet::storage]
+	#[pallet::getter(fn paused)]
+	pub type Paused<T: Config> = StorageValue<_, PauseRecord, OptionQuery>;
+
+	/// The configuration of the order fee funding.
+	///
+	/// If set, the fee of each order is transferred from the configured source account to the
+	/// parachain's sovereign account on the RegionX parachain before the order is made.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_funding)]
+	pub type FeeFunding<T: Config> = StorageValue<_, FeeFundingConfigOf<T>, OptionQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultOrderCallWeight() -> Weight {
+		DEFAULT_ORDER_CALL_WEIGHT
+	}
+
+	#[pallet::type_value]
+	pub fn DefaultFeeMultiplier() -> FixedU128 {
+		FixedU128::one()
+	}
+
+	/// The weight of the order creation call on the RegionX parachain.
+	///
+	/// Should be updated by the `AdminOrigin` whenever the weight of the call changes on the
+	/// RegionX parachain.
+	#[pallet::storage]
+	#[pallet::getter(fn order_call_weight)]
+	pub type OrderCallWeight<T: Config> =
+		StorageValue<_, Weight, ValueQuery, DefaultOrderCallWeight>;
+
+	/// The multiplier applied to the estimated fee of an order.
+	///
+	/// Allows paying more than the estimate to account for changes in the fee on RegionX.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_multiplier)]
+	pub type FeeMultiplier<T: Config> =
+		StorageValue<_, FixedU128, ValueQuery, DefaultFeeMultiplier>;
+
+	/// Orders which are due and waiting to be sent.
+	///
+	/// The `next_attempt` of each order is the block at which the order is sent in
+	/// `on_initialize` if it wasn't sent in `on_idle` before.
+	#[pallet::storage]
+	#[pallet::getter(fn queued_orders)]
+	pub type QueuedOrders<T: Config> =
+		StorageMap<_, Twox64Concat, OrderId, RetryRecord<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The earliest `next_attempt` of the `QueuedOrders`.
+	///
+	/// Allows `on_initialize` to skip the queued orders until one of them is due. It may be
+	/// earlier than the actual earliest `next_attempt` after orders were sent in `on_idle`.
+	#[pallet::storage]
+	pub type QueuedOrdersDue<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Orders which failed to be dispatched and are scheduled to be attempted again.
+	///
+	/// Once its `next_attempt` is reached, a retry joins the `QueuedOrders`.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_retries)]
+	pub type PendingRetries<T: Config> =
+		StorageMap<_, Twox64Concat, OrderId, RetryRecord<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The earliest `next_attempt` of the `PendingRetries`.
+	///
+	/// Allows `on_initialize` to skip the pending retries until one of them is due. It may be
+	/// earlier than the actual earliest `next_attempt` after retries were abandoned.
+	#[pallet::storage]
+	pub type PendingRetriesDue<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The history of the orders made by the parachain.
 	///
-	/// Orders will be made based on this.
+	/// Only the last `T::MaxOrderHistory` orders are kept. Older orders are only removed once
+	/// their status is final.
 	#[pallet::storage]
-	#[pallet::getter(fn coretime_requirements)]
-	pub type CoretimeRequirements<T: Config> = StorageValue<_, GenericRequirements, OptionQuery>;
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> =
+		StorageMap<_, Twox64Concat, OrderId, OrderRecordOf<T>, OptionQuery>;
+
+	/// The identifier of the oldest order kept in the order history.
+	#[pallet::storage]
+	#[pallet::getter(fn oldest_order_id)]
+	pub type OldestOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// The orders whose outcome is yet to be reported by the RegionX parachain.
+	///
+	/// Indexed by the id of the query registered for the order. Also holds the retry which is
+	/// scheduled in case RegionX rejects the order.
+	#[pallet::storage]
+	#[pallet::getter(fn order_queries)]
+	pub type OrderQueries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		QueryId,
+		(OrderId, RetryRecord<BlockNumberFor<T>>),
+		OptionQuery,
+	>;
+
+	/// The queries of `OrderQueries`, indexed by the block at which they expire.
+	#[pallet::storage]
+	#[pallet::getter(fn query_expiries)]
+	pub type QueryExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		QueryId,
+		(),
+		OptionQuery,
+	>;
+
+	/// The identifier which will be assigned to the next order.
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The configuration of the Coretime chain.
+		pub configuration: Option<GenesisConfigRecord<CoretimeBlockNumberOf<T>>>,
+		/// The timeslice at which the first order should be made.
+		pub next_order: Option<Timeslice>,
+		/// The coretime requirement profiles of the parachain.
+		pub requirement_profiles: Vec<(Vec<u8>, RequirementProfile)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			if let Some(configuration) = &self.configuration {
+				let configuration = configuration.clone().into_config_record();
+				Pallet::<T>::ensure_valid_configuration(&configuration)
+					.expect("Invalid Coretime chain configuration");
+				Configuration::<T>::put(configuration);
+			}
+
+			if let Some(next_order) = self.next_order {
+				// Without the configuration we can't tell which regions to order.
+				assert!(
+					self.configuration.is_some(),
+					"The next order cannot be scheduled without the Coretime chain configuration"
+				);
+				NextOrder::<T>::put(next_order);
+			}
+
+			let mut profiles = BoundedBTreeMap::new();
+			for (name, profile) in &self.requirement_profiles {
+				let name = ProfileName::try_from(name.clone()).expect("Profile name too long");
+				Pallet::<T>::ensure_valid_profile(profile).expect("Invalid requirement profile");
+				let record = ProfileRecord {
+					profile: profile.clone(),
+					next_order: self.next_order.unwrap_or_default(),
+				};
+				profiles.try_insert(name, record).expect("Too many requirement profiles");
+			}
+			RequirementProfiles::<T>::put(profiles);
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -148,77 +477,223 @@ pub mod pallet {
 		ConfigurationSet { configuration: ConfigRecordOf<T> },
 		/// Timeslice for the next order was set.
 		NextOrderScheduled { next_order: Timeslice },
-		/// Coretime requirements got set.
+		/// A requirement profile got set.
+		///
+		/// If `None` it means that the profile got removed.
+		RequirementProfileSet { name: ProfileName, profile: Option<RequirementProfile> },
+		/// A change to the requirements of a profile got scheduled.
+		RequirementsChangeScheduled {
+			profile: ProfileName,
+			at: Timeslice,
+			requirements: GenericRequirements,
+		},
+		/// A scheduled requirements change got cancelled.
+		RequirementsChangeCancelled { profile: ProfileName, at: Timeslice },
+		/// A scheduled requirements change took effect.
+		RequirementsChangeApplied { profile: ProfileName, requirements: GenericRequirements },
+		/// The order fee funding configuration got set.
+		///
+		/// If `None` the fees are no longer topped up by the pallet.
+		FeeFundingSet { funding: Option<FeeFundingConfigOf<T>> },
+		/// The fee of an order was transferred to the sovereign account on the RegionX parachain.
+		OrderFeeFunded { source: T::AccountId, amount: T::RelaychainBalance },
+		/// Failed to top up the sovereign account with the fee of an order.
+		///
+		/// The order will still be attempted with the funds present in the sovereign account.
+		OrderFeeFundingFailed { error: DispatchError },
+		/// The weight of the order creation call and the fee multiplier got set.
+		OrderCallWeightSet { call_weight: Weight, fee_multiplier: FixedU128 },
+		/// The orders of bulk periods which already ended were skipped.
+		///
+		/// `count` orders were skipped, the first of which should have been made at the `from`
+		/// timeslice. The skipped orders are one bulk period apart.
+		MissedOrdersSkipped { from: Timeslice, count: u32 },
+		/// A new order was created.
+		OrderCreated { order_id: OrderId, profile: ProfileName, requirements: OrderRequirements },
+		/// The Coretime chain configuration and the order schedule were synced with the state of
+		/// the Coretime chain.
+		CoretimeStateSynced { configuration: ConfigRecordOf<T>, next_order: Timeslice },
+		/// An order was made manually by the `AdminOrigin`.
+		OrderForced { order_id: OrderId },
+		/// The order couldn't be sent to the RegionX parachain.
+		OrderDispatchFailed { order_id: OrderId, error: SendError },
+		/// A failed order will be attempted again at the specified block.
+		///
+		/// `attempt` is the number of the upcoming retry.
+		OrderRetryScheduled { order_id: OrderId, attempt: u32, at: BlockNumberFor<T> },
+		/// A failed order won't be attempted again.
 		///
-		/// If `None` it means that the parachain will stop with Coretime procurement.
-		CoretimeRequirementSet { requirements: Option<GenericRequirements> },
+		/// Either the retry limit was reached or the order is no longer useful.
+		OrderAbandoned { order_id: OrderId },
+		/// The RegionX parachain reported that the order was successfully created.
+		OrderAcknowledged { order_id: OrderId },
+		/// The RegionX parachain failed to create the order.
+		OrderRejected { order_id: OrderId, error: XcmError },
+		/// The RegionX parachain didn't report the outcome of the order in time.
+		///
+		/// The order won't be attempted again, since it may have been created.
+		OrderResponseTimedOut { order_id: OrderId },
+		/// The order creation was paused.
+		OrderCreationPaused { until: Option<Timeslice> },
+		/// The order creation was resumed.
+		OrderCreationResumed { next_order: Option<Timeslice> },
+		/// The message which will be sent to the RegionX parachain for one of the next orders.
+		OrderMessagePreviewed {
+			profile: ProfileName,
+			destination: Box<MultiLocation>,
+			message: VersionedXcm<()>,
+		},
 	}
 
 	#[pallet::error]
 	#[derive(PartialEq)]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// The RegionX parachain is not reachable from this chain.
+		Unreachable,
+		/// There was an issue with the transport mechanism when sending the order.
+		Transport,
+		/// The RegionX parachain doesn't support the format of the order message.
+		DestinationUnsupported,
+		/// The order message exceeds the maximum size allowed by the transport layer.
+		ExceedsMaxMessageSize,
+		/// The order message is missing a required argument.
+		MissingArgument,
+		/// The delivery fees of the order message couldn't be paid.
+		DeliveryFees,
+		/// The fee funding source doesn't have enough funds to cover the order fee.
+		InsufficientFeeFunds,
+		/// The weight of the order creation call cannot be zero.
+		InvalidCallWeight,
+		/// The fee multiplier cannot be zero.
+		InvalidFeeMultiplier,
+		/// The region length of the Coretime chain configuration cannot be zero.
+		InvalidRegionLength,
+		/// The lead-in length of the Coretime chain configuration cannot be zero.
+		InvalidLeadinLength,
+		/// The core occupancy must be greater than zero and cannot exceed the entire core.
+		InvalidCoreOccupancy,
+		/// The occupancy bounds must be ordered and within the valid core occupancy range.
+		InvalidOccupancyBounds,
+		/// The duration of the required coretime cannot be zero.
+		InvalidRegionDuration,
+		/// The required coretime must be split into at least one and at most
+		/// `T::MaxRegionSplit` orders.
+		InvalidRegionSplit,
+		/// The cadence of a requirement profile cannot be zero.
+		InvalidCadence,
+		/// The maximum number of requirement profiles was reached.
+		TooManyProfiles,
+		/// There is no requirement profile with the specified name.
+		UnknownProfile,
+		/// The maximum number of scheduled requirement changes was reached.
+		TooManyScheduledChanges,
+		/// Requirement changes can only be scheduled for future timeslices.
+		InvalidChangeTimeslice,
+		/// There is no requirements change scheduled for the specified profile and timeslice.
+		UnknownChange,
+		/// There are no upcoming orders to preview.
+		NoUpcomingOrders,
+		/// The order creation can only be paused until a future timeslice.
+		InvalidPauseTimeslice,
+		/// The order creation is not paused.
+		NotPaused,
+		/// The Coretime chain configuration is not set.
+		MissingConfiguration,
+		/// The region of an order must end after it begins.
+		InvalidOrderRegion,
+		/// The relay chain state root is not available.
+		RelayStateRootUnavailable,
+		/// The state proof couldn't be verified against the relay chain state root.
+		InvalidStateProof,
+		/// The state proof shows that the Coretime chain state doesn't exist.
+		MissingCoretimeState,
+		/// There is no order waiting for a response to the specified query.
+		UnknownQuery,
+		/// The response doesn't report the outcome of an order.
+		UnexpectedResponse,
+		/// The response doesn't come from the RegionX parachain.
+		UnexpectedResponder,
+		/// Orders can only be forced for regions beginning in the future.
+		RegionAlreadyStarted,
+		/// The state proofs exceed `MaxStateProofSize`.
+		StateProofTooLarge,
+	}
+
+	impl<T> From<SendError> for Error<T> {
+		fn from(e: SendError) -> Self {
+			match e {
+				SendError::NotApplicable | SendError::Unroutable => Error::<T>::Unreachable,
+				SendError::Transport(_) => Error::<T>::Transport,
+				SendError::DestinationUnsupported => Error::<T>::DestinationUnsupported,
+				SendError::ExceedsMaxMessageSize => Error::<T>::ExceedsMaxMessageSize,
+				SendError::MissingArgument => Error::<T>::MissingArgument,
+				SendError::Fees => Error::<T>::DeliveryFees,
+			}
+		}
+	}
+
+	impl<T> From<StateProofError> for Error<T> {
+		fn from(e: StateProofError) -> Self {
+			match e {
+				StateProofError::InvalidProof | StateProofError::InvalidValue =>
+					Error::<T>::InvalidStateProof,
+				StateProofError::MissingValue => Error::<T>::MissingCoretimeState,
+			}
+		}
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
-			let mut weight = T::DbWeight::get().reads(1);
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// The expired queries are only kept until their expiry block, so they are processed
+			// even while the order creation is paused.
+			let mut weight = Self::expire_queries(now);
 
-			let Some(config) = Configuration::<T>::get() else {
-				log::warn!(
-					target: LOG_TARGET,
-					"Coretime chain configuration not set",
-				);
-				return weight;
+			weight += T::DbWeight::get().reads(1);
+			if let Some(pause) = Paused::<T>::get() {
+				match pause.until {
+					Some(until) if Self::current_timeslice() >= until => {
+						weight += T::WeightInfo::resume();
+						Self::do_resume();
+					},
+					_ => return weight,
+				}
+			}
+
+			weight += Self::process_retries(now);
+
+			// Queueing the due orders is benchmarked as a whole.
+			weight += match Self::make_orders(now) {
+				0 => T::WeightInfo::on_initialize_idle(),
+				orders => T::WeightInfo::on_initialize_order(orders),
 			};
 
-			weight += T::DbWeight::get().reads(1);
-			let Some(next_order) = NextOrder::<T>::get() else {
-				log::warn!(
-					target: LOG_TARGET,
-					"The timeslice for the next order not set",
-				);
+			// Orders which waited too long for spare block weight are sent right away.
+			weight.saturating_add(Self::process_queued_orders(now))
+		}
+
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			if Paused::<T>::exists() {
 				return weight;
-			};
+			}
 
-			if Self::current_timeslice() >= next_order {
-				weight += T::DbWeight::get().reads(1);
-				let Some(generic) = CoretimeRequirements::<T>::get() else {
-					log::warn!(
-						target: LOG_TARGET,
-						"The coretime requirements are not set",
-					);
-					return weight;
-				};
-				// From here on we treat the `next_order` as the current order.
-				let current_order = next_order;
-
-				// We are making the order at the start of the bulk period, so the region we are
-				// looking for should cover the upcoming bulk period.
-				//
-				// `region_length` is always exactly one bulk period.
-				let region_begin = current_order.saturating_add(config.region_length);
-				let region_end = region_begin.saturating_add(config.region_length);
-
-				let requirements = OrderRequirements {
-					begin: region_begin,
-					end: region_end,
-					core_occupancy: generic.core_occupancy,
-				};
-				weight.saturating_add(T::WeightInfo::dispatch());
-				if let Err(e) = T::OrderDispatcher::dispatch(requirements) {
-					log::error!(
-						target: LOG_TARGET,
-						"Failed to dispatch order: {:?}",
-						e
-					);
-				}
-				weight += T::DbWeight::get().writes(1);
-				NextOrder::<T>::set(Some(region_begin));
+			let order_weight =
+				T::WeightInfo::send_queued_order().saturating_add(T::DbWeight::get().reads(1));
+			let max_orders = remaining_weight
+				.saturating_sub(weight)
+				.checked_div_per_component(&order_weight)
+				.unwrap_or(u64::MAX);
+			if max_orders == 0 {
+				return weight;
+			}
 
-				weight
-			} else {
-				weight
+			for (order_id, order) in QueuedOrders::<T>::drain().take(max_orders.saturated_into()) {
+				weight += order_weight;
+				Self::send_queued_order(now, order_id, order);
 			}
+
+			weight
 		}
 	}
 
@@ -235,6 +710,7 @@ pub mod pallet {
 			configuration: ConfigRecordOf<T>,
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			Self::ensure_valid_configuration(&configuration)?;
 
 			Configuration::<T>::put(configuration.clone());
 			Self::deposit_event(Event::ConfigurationSet { configuration });
@@ -255,30 +731,1038 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Extrinsic for creating an order.
+		/// Set or remove a coretime requirement profile.
+		///
+		/// Updating an existing profile keeps its order schedule. New profiles make their first
+		/// orders at `NextOrder`.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `name`: The name of the profile.
+		/// - `profile`: The requirement profile. If set to `None` the profile is removed together
+		///   with its scheduled requirement changes, and no more orders are made for it.
+		///
+		/// This replaces `set_coretime_requirements`. Its call index 2 is retired rather than
+		/// reused, so that transactions encoded for the old call fail to decode instead of being
+		/// dispatched with a different meaning.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_requirement_profile())]
+		pub fn set_requirement_profile(
+			origin: OriginFor<T>,
+			name: ProfileName,
+			profile: Option<RequirementProfile>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+
+			RequirementProfiles::<T>::try_mutate(|profiles| -> DispatchResult {
+				let Some(profile) = profile.clone() else {
+					profiles.remove(&name);
+					ScheduledChanges::<T>::mutate(|changes| {
+						changes.retain(|change| change.profile != name)
+					});
+					return Ok(());
+				};
+				Self::ensure_valid_profile(&profile)?;
+
+				if let Some(record) = profiles.get_mut(&name) {
+					record.profile = profile;
+				} else {
+					let next_order = NextOrder::<T>::get().unwrap_or_default();
+					profiles
+						.try_insert(name.clone(), ProfileRecord { profile, next_order })
+						.map_err(|_| Error::<T>::TooManyProfiles)?;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RequirementProfileSet { name, profile });
+			Ok(())
+		}
+
+		/// Set the account from which the order fees are funded.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `funding`: The fee funding configuration. If set to `None` the pallet will stop
+		///   topping up the sovereign account on the RegionX parachain.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_fee_funding())]
+		pub fn set_fee_funding(
+			origin: OriginFor<T>,
+			funding: Option<FeeFundingConfigOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+
+			FeeFunding::<T>::set(funding.clone());
+			Self::deposit_event(Event::FeeFundingSet { funding });
+			Ok(())
+		}
+
+		/// Set the weight of the order creation call on the RegionX parachain.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `call_weight`: The weight of the order creation call on the RegionX parachain.
+		/// - `fee_multiplier`: The multiplier applied to the fee estimated from `call_weight`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_order_call_weight())]
+		pub fn set_order_call_weight(
+			origin: OriginFor<T>,
+			call_weight: Weight,
+			fee_multiplier: FixedU128,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			ensure!(call_weight.any_gt(Weight::zero()), Error::<T>::InvalidCallWeight);
+			ensure!(!fee_multiplier.is_zero(), Error::<T>::InvalidFeeMultiplier);
+
+			OrderCallWeight::<T>::put(call_weight);
+			FeeMultiplier::<T>::put(fee_multiplier);
+			Self::deposit_event(Event::OrderCallWeightSet { call_weight, fee_multiplier });
+			Ok(())
+		}
+
+		/// Record the outcome of an order reported by the RegionX parachain.
+		///
+		/// Rejected orders are retried as long as they are still useful.
+		///
+		/// - `origin`: Must pass `XcmResponseOrigin`.
+		/// - `query_id`: The id of the query registered when the order was sent.
+		/// - `response`: The outcome of the order execution on the RegionX parachain.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::notify_order_status())]
+		pub fn notify_order_status(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::XcmResponseOrigin::ensure_origin(origin)?;
+			ensure!(responder == T::RegionXLocation::get(), Error::<T>::UnexpectedResponder);
+
+			let (order_id, retry) =
+				OrderQueries::<T>::get(query_id).ok_or(Error::<T>::UnknownQuery)?;
+			let Response::ExecutionResult(outcome) = response else {
+				return Err(Error::<T>::UnexpectedResponse.into());
+			};
+			OrderQueries::<T>::remove(query_id);
+
+			match outcome {
+				None => {
+					Self::set_order_status(order_id, OrderStatus::Acknowledged);
+					Self::deposit_event(Event::OrderAcknowledged { order_id });
+				},
+				Some((_, error)) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Order {} was rejected: {:?}",
+						order_id,
+						error
+					);
+					Self::set_order_status(order_id, OrderStatus::Failed);
+					Self::deposit_event(Event::OrderRejected { order_id, error });
+
+					// The order is abandoned instead if it is no longer useful.
+					let now = frame_system::Pallet::<T>::block_number();
+					Self::schedule_retry(now, order_id, retry);
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Schedule a change to the requirements of a profile.
+		///
+		/// Scheduling a change for a profile and timeslice which already have a change scheduled
+		/// replaces it.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `profile`: The name of the profile whose requirements are changed.
+		/// - `at`: The timeslice from which the new requirements apply. The change is applied to
+		///   the orders for regions beginning at or after this timeslice.
+		/// - `requirements`: The new requirements of the profile.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::schedule_requirements_change())]
+		pub fn schedule_requirements_change(
+			origin: OriginFor<T>,
+			profile: ProfileName,
+			at: Timeslice,
+			requirements: GenericRequirements,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			ensure!(
+				RequirementProfiles::<T>::get().contains_key(&profile),
+				Error::<T>::UnknownProfile
+			);
+			ensure!(at > Self::current_timeslice(), Error::<T>::InvalidChangeTimeslice);
+			Self::ensure_valid_requirements(&requirements)?;
+
+			ScheduledChanges::<T>::try_mutate(|changes| -> DispatchResult {
+				changes.retain(|change| change.profile != profile || change.at != at);
+				let index = changes.partition_point(|change| change.at <= at);
+				let change = ScheduledChange {
+					profile: profile.clone(),
+					at,
+					requirements: requirements.clone(),
+				};
+				changes
+					.try_insert(index, change)
+					.map_err(|_| Error::<T>::TooManyScheduledChanges)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RequirementsChangeScheduled { profile, at, requirements });
+			Ok(())
+		}
+
+		/// Cancel a scheduled requirements change.
 		///
-		/// ## Arguments:
-		/// - `requirements`: The coretime requirements of the parachain. If set to `None` the
-		///   pallet will stop with the order creation.
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::set_coretime_requirements())]
-		pub fn set_coretime_requirements(
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `profile`: The name of the profile for which the change is scheduled.
+		/// - `at`: The timeslice from which the change would apply.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_requirements_change())]
+		pub fn cancel_requirements_change(
 			origin: OriginFor<T>,
-			requirements: Option<GenericRequirements>,
+			profile: ProfileName,
+			at: Timeslice,
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin_or_root(origin)?;
 
-			CoretimeRequirements::<T>::set(requirements.clone());
-			Self::deposit_event(Event::CoretimeRequirementSet { requirements });
+			ScheduledChanges::<T>::try_mutate(|changes| -> DispatchResult {
+				let index = changes
+					.iter()
+					.position(|change| change.profile == profile && change.at == at)
+					.ok_or(Error::<T>::UnknownChange)?;
+				changes.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RequirementsChangeCancelled { profile, at });
+			Ok(())
+		}
+
+		/// Emits the exact messages which will be sent to the RegionX parachain for the next
+		/// orders, without sending them.
+		///
+		/// Allows reviewing the orders before enabling the pallet. Doesn't modify any state. The
+		/// call is weighed for the most orders that can be made at once.
+		///
+		/// - `origin`: Must be Signed or Root.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::preview_order_messages(
+			T::MaxProfiles::get().saturating_mul(T::MaxRegionSplit::get())
+		))]
+		pub fn preview_order_messages(origin: OriginFor<T>) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+
+			let messages = Self::next_order_messages()?;
+			ensure!(!messages.is_empty(), Error::<T>::NoUpcomingOrders);
+
+			for (profile, destination, message) in messages {
+				Self::deposit_event(Event::OrderMessagePreviewed {
+					profile,
+					destination: Box::new(destination),
+					message,
+				});
+			}
 			Ok(())
 		}
+
+		/// Pause the order creation without modifying the requirement profiles.
+		///
+		/// Pausing an already paused order creation updates the timeslice until which it is
+		/// paused.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `until`: The timeslice at which the order creation resumes by itself. If set to `None`
+		///   the order creation stays paused until `resume` is called.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, until: Option<Timeslice>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			if let Some(until) = until {
+				ensure!(until > Self::current_timeslice(), Error::<T>::InvalidPauseTimeslice);
+			}
+
+			Paused::<T>::put(PauseRecord { until });
+			Self::deposit_event(Event::OrderCreationPaused { until });
+			Ok(())
+		}
+
+		/// Resume the paused order creation.
+		///
+		/// Orders for the bulk periods which started while paused are not made.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::resume())]
+		pub fn resume(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			ensure!(Paused::<T>::exists(), Error::<T>::NotPaused);
+
+			Self::do_resume();
+			Ok(())
+		}
+
+		/// Make an order right away, independently of the order schedule.
+		///
+		/// The order is recorded in the order history but `NextOrder` is left untouched. Forced
+		/// orders are not retried if they fail, they are abandoned instead. An order which fails
+		/// to be sent is kept in the order history as well.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `profile`: The requirement profile the order is made for.
+		/// - `requirements`: The requirements of the order, whose region has to begin in the
+		///   future. If set to `None`, the orders for the upcoming bulk period are made based on
+		///   the requirements of the profile.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::force_order(
+			requirements.as_ref().map_or(T::MaxRegionSplit::get(), |_| 1)
+		))]
+		pub fn force_order(
+			origin: OriginFor<T>,
+			profile: ProfileName,
+			requirements: Option<OrderRequirements>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			let record = RequirementProfiles::<T>::get()
+				.get(&profile)
+				.cloned()
+				.ok_or(Error::<T>::UnknownProfile)?;
+
+			let orders = match requirements {
+				Some(requirements) => {
+					ensure!(requirements.begin < requirements.end, Error::<T>::InvalidOrderRegion);
+					ensure!(
+						requirements.begin > Self::current_timeslice(),
+						Error::<T>::RegionAlreadyStarted
+					);
+					ensure!(
+						requirements.core_occupancy > 0 &&
+							requirements.core_occupancy <= CORE_PARTS,
+						Error::<T>::InvalidCoreOccupancy
+					);
+					vec![requirements]
+				},
+				None => {
+					let config =
+						Configuration::<T>::get().ok_or(Error::<T>::MissingConfiguration)?;
+					let next_order = NextOrder::<T>::get().unwrap_or_default();
+					// The region of the current bulk period has already started, so the orders
+					// are made for the upcoming one.
+					let period_begin = Self::current_period_begin(next_order, config.region_length)
+						.saturating_add(config.region_length);
+					record
+						.profile
+						.requirements
+						.with_usage(T::UsageProvider::core_usage())
+						.orders(period_begin, config.region_length)
+				},
+			};
+
+			let now = frame_system::Pallet::<T>::block_number();
+			// The deadline is already reached, so the orders are abandoned instead of retried if
+			// the RegionX parachain rejects them.
+			let deadline = Self::current_timeslice();
+			for requirements in orders {
+				if let Err(error) = Self::fund_order_fee() {
+					Self::deposit_event(Event::OrderFeeFundingFailed { error });
+				}
+
+				let order_id = Self::record_order(now, profile.clone(), requirements.clone());
+				Self::deposit_event(Event::OrderForced { order_id });
+
+				// The failure is recorded, but doesn't revert the orders which were already sent.
+				let retry = RetryRecord { requirements, attempts: 1, next_attempt: now, deadline };
+				if Self::dispatch_order(now, order_id, &retry).is_err() {
+					Self::abandon_order(order_id);
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Sync the Coretime chain configuration and the order schedule with the state of the
+		/// Coretime chain.
+		///
+		/// The state of the Coretime chain is proved against the latest relay chain state root
+		/// known to the parachain, rather than taken from the origin.
+		///
+		/// If `NextOrder` isn't aligned with the bulk periods of the Coretime chain, it is set to
+		/// the start of the bulk period in which the regions of the ongoing sale are ordered.
+		///
+		/// Callable by anyone, since the state is verified. The call is free if it changes the
+		/// configuration or the order schedule.
+		///
+		/// - `origin`: Signed origin.
+		/// - `relay_proof`: Proof of the head of the Coretime chain in the relay chain state.
+		/// - `coretime_proof`: Proof of the `Configuration` and `SaleInfo` of `pallet_broker` in
+		///   the state of the Coretime chain.
+		///
+		/// The combined size of the proofs can't exceed `MaxStateProofSize`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::sync_coretime_state(state_proofs_size(
+			relay_proof,
+			coretime_proof
+		)))]
+		pub fn sync_coretime_state(
+			origin: OriginFor<T>,
+			relay_proof: StorageProof,
+			coretime_proof: StorageProof,
+		) -> DispatchResultWithPostInfo {
+			let _who = ensure_signed(origin)?;
+			ensure!(
+				state_proofs_size(&relay_proof, &coretime_proof) <= T::MaxStateProofSize::get(),
+				Error::<T>::StateProofTooLarge
+			);
+			let relay_state_root = T::RelayStateRootProvider::relay_state_root()
+				.ok_or(Error::<T>::RelayStateRootUnavailable)?;
+
+			let state = verify_coretime_state::<T::CoretimeChain>(
+				relay_state_root,
+				T::CoretimeParaId::get(),
+				relay_proof,
+				coretime_proof,
+			)
+			.map_err(Error::<T>::from)?;
+
+			let configuration: ConfigRecordOf<T> = state.config_record();
+			Self::ensure_valid_configuration(&configuration)?;
+
+			let region_length = configuration.region_length;
+			let region_begin = state.sale_info.region_begin;
+			let next_order = match NextOrder::<T>::get() {
+				Some(next_order) if next_order.abs_diff(region_begin) % region_length == 0 =>
+					next_order,
+				_ => region_begin.saturating_sub(region_length),
+			};
+
+			// Submitting proofs of the state which is already known is paid for.
+			let changed = Configuration::<T>::get().as_ref() != Some(&configuration) ||
+				NextOrder::<T>::get() != Some(next_order);
+
+			Configuration::<T>::put(configuration.clone());
+			NextOrder::<T>::put(next_order);
+			Self::deposit_event(Event::CoretimeStateSynced { configuration, next_order });
+			Ok(if changed { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		pub(crate) fn current_timeslice() -> Timeslice {
+		/// The current timeslice on the Coretime chain, derived from the relay chain block number.
+		pub fn current_timeslice() -> Timeslice {
 			let latest_rc_block = T::RCBlockNumberProvider::current_block_number();
 			let timeslice_period = T::TimeslicePeriod::get();
 			(latest_rc_block / timeslice_period).saturated_into()
 		}
+
+		/// The timeslice at which the lead-in period of a sale starting now ends.
+		///
+		/// The interlude and lead-in lengths are expressed in Coretime chain blocks, so they are
+		/// converted to relay chain blocks first.
+		pub(crate) fn leadin_end(config: &ConfigRecordOf<T>) -> Timeslice {
+			let sale_blocks: u32 =
+				config.interlude_length.saturating_add(config.leadin_length).saturated_into();
+			let sale_rc_blocks = RCBlockNumberOf::<T>::from(sale_blocks)
+				.saturating_mul(T::CoretimeBlockPeriod::get());
+			let latest_rc_block = T::RCBlockNumberProvider::current_block_number();
+			(latest_rc_block.saturating_add(sale_rc_blocks) / T::TimeslicePeriod::get())
+				.saturated_into()
+		}
+
+		/// The start of the bulk period the current timeslice is in.
+		///
+		/// The bulk periods are aligned to `next_order`, which is always the start of one.
+		pub(crate) fn current_period_begin(
+			next_order: Timeslice,
+			region_length: Timeslice,
+		) -> Timeslice {
+			let current_timeslice = Self::current_timeslice();
+			if current_timeslice >= next_order {
+				let missed_periods =
+					Self::missed_periods(next_order, current_timeslice, region_length);
+				next_order.saturating_add(missed_periods.saturating_mul(region_length))
+			} else {
+				let periods_ahead = (next_order - current_timeslice).div_ceil(region_length);
+				next_order.saturating_sub(periods_ahead.saturating_mul(region_length))
+			}
+		}
+
+		/// The number of bulk periods which started since `next_order` without us ordering
+		/// coretime for them.
+		pub(crate) fn missed_periods(
+			next_order: Timeslice,
+			current_timeslice: Timeslice,
+			region_length: Timeslice,
+		) -> Timeslice {
+			current_timeslice
+				.saturating_sub(next_order)
+				.checked_div(region_length)
+				.unwrap_or_default()
+		}
+
+		/// Returns the requirements of the orders which will be made next, together with the
+		/// profile each of them is made for.
+		///
+		/// Plans the orders the same way `on_initialize` does, without modifying any state. If the
+		/// next order is already due, the orders which would be made in the current block are
+		/// returned. While the order creation is paused, the orders which will be made once it
+		/// resumes by itself are returned, or none if it is paused until explicitly resumed.
+		pub fn next_orders() -> Vec<(ProfileName, OrderRequirements)> {
+			let (Some(config), Some(next_order)) =
+				(Configuration::<T>::get(), NextOrder::<T>::get())
+			else {
+				return Default::default();
+			};
+
+			let mut timeslice = Self::current_timeslice();
+			if let Some(pause) = Paused::<T>::get() {
+				let Some(until) = pause.until else {
+					return Default::default();
+				};
+				timeslice = timeslice.max(until);
+			}
+
+			let mut profiles = RequirementProfiles::<T>::get();
+			Self::plan_orders(
+				&config,
+				next_order,
+				timeslice,
+				&mut profiles,
+				&ScheduledChanges::<T>::get(),
+				T::UsageProvider::core_usage(),
+			)
+			.orders
+		}
+
+		/// Returns the messages which will be sent to the RegionX parachain for the next orders,
+		/// together with their destination and the profile each of them is made for.
+		///
+		/// All state changes made while building the messages are reverted.
+		pub fn next_order_messages(
+		) -> Result<Vec<(ProfileName, MultiLocation, VersionedXcm<()>)>, DispatchError> {
+			storage::with_transaction(|| {
+				let messages = Self::next_orders()
+					.into_iter()
+					.map(|(profile, requirements)| {
+						let (destination, message) =
+							T::OrderDispatcher::order_message(requirements)
+								.map_err(Error::<T>::from)?;
+						Ok((profile, destination, VersionedXcm::from(message)))
+					})
+					.collect();
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(messages))
+			})?
+		}
+
+		/// Makes the orders of all the requirement profiles whose orders are due.
+		///
+		/// Returns the number of orders that were made.
+		pub(crate) fn make_orders(now: BlockNumberFor<T>) -> u32 {
+			let Some(config) = Configuration::<T>::get() else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Coretime chain configuration not set",
+				);
+				return 0;
+			};
+
+			let Some(next_order) = NextOrder::<T>::get() else {
+				log::warn!(
+					target: LOG_TARGET,
+					"The timeslice for the next order not set",
+				);
+				return 0;
+			};
+
+			let current_timeslice = Self::current_timeslice();
+			if current_timeslice < next_order {
+				return 0;
+			}
+
+			let mut profiles = RequirementProfiles::<T>::get();
+			if profiles.is_empty() {
+				log::warn!(
+					target: LOG_TARGET,
+					"The coretime requirements are not set",
+				);
+				return 0;
+			}
+
+			let mut changes = ScheduledChanges::<T>::get().into_inner();
+			let plan = Self::plan_orders(
+				&config,
+				next_order,
+				current_timeslice,
+				&mut profiles,
+				&changes,
+				T::UsageProvider::core_usage(),
+			);
+
+			if plan.missed_periods > 0 {
+				Self::deposit_event(Event::MissedOrdersSkipped {
+					from: next_order,
+					count: plan.missed_periods,
+				});
+			}
+
+			if plan.applied_changes > 0 {
+				let pending = changes.split_off(plan.applied_changes);
+				for change in changes {
+					// Changes of profiles which no longer exist are dropped.
+					if !profiles.contains_key(&change.profile) {
+						log::warn!(
+							target: LOG_TARGET,
+							"Dropping the requirements change of a removed profile",
+						);
+						continue;
+					}
+					Self::deposit_event(Event::RequirementsChangeApplied {
+						profile: change.profile,
+						requirements: change.requirements,
+					});
+				}
+				ScheduledChanges::<T>::put(BoundedVec::truncate_from(pending));
+			}
+
+			// The order is only useful until the end of the sale's lead-in period.
+			let deadline = Self::leadin_end(&config);
+
+			let mut orders_made: u32 = 0;
+			for (name, requirements) in plan.orders {
+				// The order is sent once there is enough spare block weight.
+				let order_id = Self::record_order(now, name, requirements.clone());
+				let next_attempt = now.saturating_add(T::MaxDispatchDelay::get());
+				QueuedOrders::<T>::insert(
+					order_id,
+					RetryRecord { requirements, attempts: 1, next_attempt, deadline },
+				);
+				QueuedOrdersDue::<T>::mutate(|due| {
+					*due = Some(due.map_or(next_attempt, |due| due.min(next_attempt)))
+				});
+				orders_made.saturating_inc();
+			}
+			RequirementProfiles::<T>::put(profiles);
+			NextOrder::<T>::set(Some(plan.region_begin));
+
+			orders_made
+		}
+
+		/// Resumes the order creation.
+		///
+		/// If bulk periods started while the order creation was paused, `NextOrder` is moved to
+		/// the start of the current bulk period. The orders for the upcoming bulk period are then
+		/// made right away, while the ones for the periods which already started are not.
+		pub(crate) fn do_resume() {
+			Paused::<T>::kill();
+
+			let next_order = match (Configuration::<T>::get(), NextOrder::<T>::get()) {
+				(Some(config), Some(next_order)) => {
+					let missed_periods = Self::missed_periods(
+						next_order,
+						Self::current_timeslice(),
+						config.region_length,
+					);
+					let next_order = next_order
+						.saturating_add(missed_periods.saturating_mul(config.region_length));
+					NextOrder::<T>::put(next_order);
+					Some(next_order)
+				},
+				(_, next_order) => next_order,
+			};
+
+			Self::deposit_event(Event::OrderCreationResumed { next_order });
+		}
+
+		/// Ensures the Coretime chain configuration can be used for making orders.
+		pub(crate) fn ensure_valid_configuration(
+			configuration: &ConfigRecordOf<T>,
+		) -> Result<(), Error<T>> {
+			// Otherwise we would keep ordering the same region every block.
+			ensure!(configuration.region_length > 0, Error::<T>::InvalidRegionLength);
+			ensure!(!configuration.leadin_length.is_zero(), Error::<T>::InvalidLeadinLength);
+			Ok(())
+		}
+
+		/// Ensures the coretime requirements describe valid regions.
+		pub(crate) fn ensure_valid_requirements(
+			requirements: &GenericRequirements,
+		) -> Result<(), Error<T>> {
+			ensure!(
+				requirements.core_occupancy > 0 && requirements.core_occupancy <= CORE_PARTS,
+				Error::<T>::InvalidCoreOccupancy
+			);
+			if let Some(bounds) = &requirements.occupancy_bounds {
+				ensure!(
+					bounds.min > 0 && bounds.min <= bounds.max && bounds.max <= CORE_PARTS,
+					Error::<T>::InvalidOccupancyBounds
+				);
+			}
+			ensure!(requirements.duration != Some(0), Error::<T>::InvalidRegionDuration);
+			ensure!(
+				requirements.split > 0 && requirements.split <= T::MaxRegionSplit::get(),
+				Error::<T>::InvalidRegionSplit
+			);
+			Ok(())
+		}
+
+		/// Ensures the requirement profile can be used for making orders.
+		pub(crate) fn ensure_valid_profile(profile: &RequirementProfile) -> Result<(), Error<T>> {
+			Self::ensure_valid_requirements(&profile.requirements)?;
+			ensure!(profile.cadence > 0, Error::<T>::InvalidCadence);
+			Ok(())
+		}
+
+		/// The fee paid for the execution of an order on the RegionX parachain.
+		///
+		/// Covers the whole message through which the order is created, not only the call.
+		pub fn order_fee() -> T::RelaychainBalance {
+			let weight = T::OrderDispatcher::order_weight(OrderCallWeight::<T>::get());
+			let fee = T::WeightToFee::weight_to_fee(&weight);
+			FeeMultiplier::<T>::get().saturating_mul_int(fee)
+		}
+
+		/// Plans the orders made at `timeslice` for the requirement `profiles`, based on the
+		/// timeslice of the `next_order`.
+		///
+		/// The scheduled `changes` which take effect for the ordered regions are applied to the
+		/// profiles, and the order schedule of the profiles for which orders are made is advanced.
+		///
+		/// Doesn't access the storage, so that the orders previewed by `next_orders` are planned
+		/// exactly the way `make_orders` makes them.
+		pub(crate) fn plan_orders(
+			config: &ConfigRecordOf<T>,
+			next_order: Timeslice,
+			timeslice: Timeslice,
+			profiles: &mut BoundedBTreeMap<ProfileName, ProfileRecord, T::MaxProfiles>,
+			changes: &[ScheduledChange],
+			usage: Option<Perbill>,
+		) -> OrderPlan {
+			// If we missed the start of one or more bulk periods there is no point in ordering
+			// coretime for them since their regions have already started. We skip straight to the
+			// bulk period we are currently in.
+			let missed_periods = Self::missed_periods(next_order, timeslice, config.region_length);
+
+			// From here on we treat the start of the current bulk period as the current order. The
+			// region we order always begins after the current timeslice.
+			let current_order =
+				next_order.saturating_add(missed_periods.saturating_mul(config.region_length));
+
+			// We are making the orders at the start of the bulk period, so the regions we are
+			// looking for should be within the upcoming bulk periods.
+			//
+			// `region_length` is always exactly one bulk period.
+			let region_begin = current_order.saturating_add(config.region_length);
+
+			let applied_changes = changes.partition_point(|change| change.at <= region_begin);
+			for change in &changes[..applied_changes] {
+				if let Some(record) = profiles.get_mut(&change.profile) {
+					record.profile.requirements = change.requirements.clone();
+				}
+			}
+
+			let mut orders = Vec::new();
+			for (name, record) in profiles.iter_mut() {
+				if !record.profile.active || record.next_order > current_order {
+					continue;
+				}
+
+				orders.extend(
+					record
+						.profile
+						.requirements
+						.with_usage(usage)
+						.orders(region_begin, config.region_length)
+						.into_iter()
+						.map(|requirements| (name.clone(), requirements)),
+				);
+
+				// The next orders of the profile are made once its cadence elapses.
+				record.next_order =
+					current_order.saturating_add(record.profile.interval(config.region_length));
+			}
+
+			OrderPlan { missed_periods, region_begin, applied_changes, orders }
+		}
+
+		/// Tops up the sovereign account on the RegionX parachain with the fee of an order.
+		///
+		/// Does nothing if the fee funding is not configured.
+		pub(crate) fn fund_order_fee() -> DispatchResult {
+			let Some(funding) = FeeFunding::<T>::get() else { return Ok(()) };
+
+			let amount = Self::order_fee();
+			ensure!(
+				T::RelaychainCurrency::balance(&funding.source) >=
+					amount.saturating_add(funding.min_balance).into(),
+				Error::<T>::InsufficientFeeFunds
+			);
+
+			T::FeeFunder::fund(&funding.source, amount)?;
+			Self::deposit_event(Event::OrderFeeFunded { source: funding.source, amount });
+			Ok(())
+		}
+
+		/// Adds a new order to the order history and returns its identifier.
+		///
+		/// The order is recorded as failed until it gets dispatched.
+		pub(crate) fn record_order(
+			now: BlockNumberFor<T>,
+			profile: ProfileName,
+			requirements: OrderRequirements,
+		) -> OrderId {
+			let order_id = NextOrderId::<T>::mutate(|next_id| {
+				let order_id = *next_id;
+				next_id.saturating_inc();
+				order_id
+			});
+
+			Orders::<T>::insert(
+				order_id,
+				OrderRecord {
+					profile: profile.clone(),
+					requirements: requirements.clone(),
+					dispatched_at: now,
+					fee: Zero::zero(),
+					status: OrderStatus::Queued,
+					query_id: None,
+				},
+			);
+			Self::prune_order_history(order_id.saturating_add(1));
+
+			Self::deposit_event(Event::OrderCreated { order_id, profile, requirements });
+			order_id
+		}
+
+		/// Removes the oldest orders exceeding `T::MaxOrderHistory` from the order history, as
+		/// long as their status is final.
+		///
+		/// At most two orders are removed at once, so the history shrinks back to its limit after
+		/// the orders holding it back get finalized, without removing an unbounded number of
+		/// orders in a single block.
+		pub(crate) fn prune_order_history(next_order_id: OrderId) {
+			let mut oldest = OldestOrderId::<T>::get();
+			for _ in 0..2 {
+				if next_order_id.saturating_sub(oldest) <= T::MaxOrderHistory::get() {
+					break;
+				}
+				if Orders::<T>::get(oldest).is_some_and(|order| !order.is_final()) {
+					break;
+				}
+				Orders::<T>::remove(oldest);
+				oldest.saturating_inc();
+			}
+			OldestOrderId::<T>::put(oldest);
+		}
+
+		/// Updates the status of an order in the order history.
+		pub(crate) fn set_order_status(order_id: OrderId, status: OrderStatus) {
+			Orders::<T>::mutate(order_id, |maybe_record| {
+				if let Some(record) = maybe_record {
+					record.status = status;
+				}
+			});
+		}
+
+		/// Sends the order to the RegionX parachain through the `OrderDispatcher`.
+		///
+		/// `retry` is the retry which gets scheduled if RegionX reports that the order failed.
+		///
+		/// In case of a failure an `OrderDispatchFailed` event is emitted.
+		pub(crate) fn dispatch_order(
+			now: BlockNumberFor<T>,
+			order_id: OrderId,
+			retry: &RetryRecord<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let maybe_query_id = match T::OrderDispatcher::dispatch(retry.requirements.clone()) {
+				Ok(maybe_query_id) => maybe_query_id,
+				Err(error) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to dispatch order {}: {:?}",
+						order_id,
+						error
+					);
+					Self::set_order_status(order_id, OrderStatus::Failed);
+					Self::deposit_event(Event::OrderDispatchFailed {
+						order_id,
+						error: error.clone(),
+					});
+					return Err(Error::<T>::from(error).into());
+				},
+			};
+
+			if let Some(query_id) = maybe_query_id {
+				OrderQueries::<T>::insert(query_id, (order_id, retry.clone()));
+				// The response is awaited until the end of the timeout block.
+				let expiry =
+					now.saturating_add(T::ResponseTimeout::get()).saturating_add(One::one());
+				QueryExpiries::<T>::insert(expiry, query_id, ());
+			}
+
+			Orders::<T>::mutate(order_id, |maybe_record| {
+				if let Some(record) = maybe_record {
+					record.dispatched_at = now;
+					record.fee = Self::order_fee();
+					record.status = OrderStatus::Dispatched;
+					record.query_id = maybe_query_id;
+				}
+			});
+			Ok(())
+		}
+
+		/// Schedules the next dispatch attempt of a failed order, or abandons it if it can't be
+		/// retried anymore.
+		pub(crate) fn schedule_retry(
+			now: BlockNumberFor<T>,
+			order_id: OrderId,
+			mut record: RetryRecord<BlockNumberFor<T>>,
+		) {
+			let delay = T::RetryDelay::get()
+				.saturating_mul(2u32.saturating_pow(record.attempts.saturating_sub(1)).into());
+			let next_attempt = now.saturating_add(delay);
+
+			if record.attempts > T::MaxRetries::get() ||
+				record.is_expired(Self::current_timeslice())
+			{
+				Self::abandon_order(order_id);
+				return;
+			}
+
+			record.next_attempt = next_attempt;
+			PendingRetries::<T>::insert(order_id, record.clone());
+			PendingRetriesDue::<T>::mutate(|due| {
+				*due = Some(due.map_or(next_attempt, |due| due.min(next_attempt)))
+			});
+			Self::deposit_event(Event::OrderRetryScheduled {
+				order_id,
+				attempt: record.attempts,
+				at: next_attempt,
+			});
+		}
+
+		/// Stops retrying a failed order.
+		pub(crate) fn abandon_order(order_id: OrderId) {
+			PendingRetries::<T>::remove(order_id);
+			Self::set_order_status(order_id, OrderStatus::Expired);
+			Self::deposit_event(Event::OrderAbandoned { order_id });
+		}
+
+		/// Funds and sends a queued order.
+		///
+		/// The order is abandoned if it is no longer useful, and retried if it fails to be sent.
+		pub(crate) fn send_queued_order(
+			now: BlockNumberFor<T>,
+			order_id: OrderId,
+			order: RetryRecord<BlockNumberFor<T>>,
+		) {
+			if order.is_expired(Self::current_timeslice()) {
+				Self::abandon_order(order_id);
+				return;
+			}
+
+			if let Err(error) = Self::fund_order_fee() {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to fund the order fee: {:?}",
+					error
+				);
+				Self::deposit_event(Event::OrderFeeFundingFailed { error });
+			}
+
+			if Self::dispatch_order(now, order_id, &order).is_err() {
+				Self::schedule_retry(now, order_id, order);
+			}
+		}
+
+		/// Sends the queued orders which weren't sent in `on_idle` within `T::MaxDispatchDelay`
+		/// blocks.
+		pub(crate) fn process_queued_orders(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			match QueuedOrdersDue::<T>::get() {
+				Some(due) if due <= now => (),
+				_ => return weight,
+			}
+
+			let queued: Vec<_> = QueuedOrders::<T>::iter().collect();
+			weight += T::DbWeight::get().reads_writes(queued.len() as u64, 1);
+
+			let (due, pending): (Vec<_>, Vec<_>) =
+				queued.into_iter().partition(|(_, order)| order.next_attempt <= now);
+			QueuedOrdersDue::<T>::set(pending.iter().map(|(_, order)| order.next_attempt).min());
+
+			for (order_id, order) in due {
+				weight += T::WeightInfo::send_queued_order();
+				QueuedOrders::<T>::remove(order_id);
+				Self::send_queued_order(now, order_id, order);
+			}
+
+			weight
+		}
+
+		/// Marks the orders whose outcome wasn't reported within `T::ResponseTimeout` as unknown.
+		///
+		/// Such orders are not retried: the order may have been created and only its report got
+		/// lost, in which case a retry would order the region twice.
+		pub(crate) fn expire_queries(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+
+			for (query_id, ()) in QueryExpiries::<T>::drain_prefix(now) {
+				weight += T::DbWeight::get().reads_writes(2, 2);
+				// The outcome of the order was already reported.
+				let Some((order_id, _)) = OrderQueries::<T>::take(query_id) else {
+					continue;
+				};
+
+				log::warn!(
+					target: LOG_TARGET,
+					"The outcome of order {} wasn't reported in time",
+					order_id
+				);
+				// Marking the outcome of the order as unknown.
+				weight += T::DbWeight::get().reads_writes(1, 1);
+				Self::set_order_status(order_id, OrderStatus::Unknown);
+				Self::deposit_event(Event::OrderResponseTimedOut { order_id });
+			}
+
+			weight
+		}
+
+		/// Queues the failed orders whose retry is due, so that they are sent the same way as new
+		/// orders.
+		pub(crate) fn process_retries(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads(1);
+			match PendingRetriesDue::<T>::get() {
+				Some(due) if due <= now => (),
+				_ => return weight,
+			}
+
+			let pending: Vec<_> = PendingRetries::<T>::iter().collect();
+			weight += T::DbWeight::get().reads_writes(pending.len() as u64, 1);
+
+			let (due, pending): (Vec<_>, Vec<_>) =
+				pending.into_iter().partition(|(_, record)| record.next_attempt <= now);
+			PendingRetriesDue::<T>::set(
+				pending.iter().map(|(_, record)| record.next_attempt).min(),
+			);
+
+			for (order_id, mut record) in due {
+				// There is no point in ordering a region which is no longer useful.
+				if record.is_expired(Self::current_timeslice()) {
+					weight += T::DbWeight::get().reads_writes(1, 2);
+					Self::abandon_order(order_id);
+					continue;
+				}
+
+				// The retry is moved to the queued orders and the earliest due one is updated.
+				weight += T::DbWeight::get().reads_writes(1, 3);
+				PendingRetries::<T>::remove(order_id);
+				record.attempts.saturating_inc();
+				record.next_attempt = now.saturating_add(T::MaxDispatchDelay::get());
+				let next_attempt = record.next_attempt;
+				QueuedOrders::<T>::insert(order_id, record);
+				QueuedOrdersDue::<T>::mutate(|due| {
+					*due = Some(due.map_or(next_attempt, |due| due.min(next_attempt)))
+				});
+			}
+
+			weight
+		}
 	}
 }
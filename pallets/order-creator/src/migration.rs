@@ -0,0 +1,199 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the order creator pallet.
+
+use crate::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade, BoundedBTreeMap};
+use pallet_broker::{ConfigRecord, PartsOf57600};
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::vec::Vec;
+use scale_info::TypeInfo;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The storage layout before the introduction of the requirement profiles.
+pub mod v0 {
+	use super::*;
+
+	/// The coretime requirements of the parachain, as stored before version 1.
+	#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+	pub struct GenericRequirements {
+		/// The minimum fraction of the core that the region should occupy.
+		pub core_occupancy: PartsOf57600,
+	}
+
+	/// The coretime requirements for the parachain.
+	#[storage_alias]
+	pub type CoretimeRequirements<T: Config> =
+		StorageValue<Pallet<T>, GenericRequirements, OptionQuery>;
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// The name of the profile into which the coretime requirements are migrated.
+	pub const MAIN_PROFILE: &[u8] = b"main";
+
+	/// Moves the coretime requirements into the active `MAIN_PROFILE` requirement profile.
+	///
+	/// The migrated requirements keep their core occupancy and get the defaults for everything
+	/// added since, so a single order covering the entire bulk period keeps being made every bulk
+	/// period.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV1 should be removed, the storage is already migrated",
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut weight = T::DbWeight::get().reads_writes(2, 2);
+			if let Some(requirements) = v0::CoretimeRequirements::<T>::take() {
+				let record = ProfileRecord {
+					profile: RequirementProfile {
+						requirements: GenericRequirements::new(requirements.core_occupancy),
+						cadence: 1,
+						active: true,
+					},
+					next_order: NextOrder::<T>::get().unwrap_or_default(),
+				};
+
+				let mut profiles = BoundedBTreeMap::new();
+				if profiles
+					.try_insert(ProfileName::truncate_from(MAIN_PROFILE.to_vec()), record)
+					.is_err()
+				{
+					log::error!(
+						target: LOG_TARGET,
+						"The coretime requirements couldn't be migrated, `MaxProfiles` is zero",
+					);
+				}
+				RequirementProfiles::<T>::put(profiles);
+				weight += T::DbWeight::get().reads_writes(1, 1);
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(v0::CoretimeRequirements::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "The storage version wasn't set");
+			ensure!(
+				!v0::CoretimeRequirements::<T>::exists(),
+				"The coretime requirements weren't removed"
+			);
+
+			let old = Option::<v0::GenericRequirements>::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the coretime requirements")?;
+			if let Some(old) = old {
+				let profile = RequirementProfiles::<T>::get()
+					.get(&ProfileName::truncate_from(MAIN_PROFILE.to_vec()))
+					.map(|record| record.profile.clone())
+					.ok_or("The main profile wasn't created")?;
+				ensure!(
+					profile.requirements == GenericRequirements::new(old.core_occupancy),
+					"The requirements weren't migrated"
+				);
+			}
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// The configuration of the Coretime chain, as stored before version 2.
+	///
+	/// The lengths of the sale periods used to be expressed in blocks of the parachain.
+	#[storage_alias]
+	pub type Configuration<T: Config> =
+		StorageValue<Pallet<T>, ConfigRecord<BlockNumberFor<T>, RCBlockNumberOf<T>>, OptionQuery>;
+
+	/// Re-encodes the stored configuration with the lengths of the sale periods expressed in
+	/// Coretime chain blocks.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"MigrateToV2 should be removed, the storage isn't at version 1",
+				);
+				return T::DbWeight::get().reads(1);
+			}
+
+			if let Some(old) = Configuration::<T>::take() {
+				crate::Configuration::<T>::put(ConfigRecordOf::<T> {
+					advance_notice: old.advance_notice,
+					interlude_length: old
+						.interlude_length
+						.saturated_into::<u128>()
+						.saturated_into(),
+					leadin_length: old.leadin_length.saturated_into::<u128>().saturated_into(),
+					region_length: old.region_length,
+					ideal_bulk_proportion: old.ideal_bulk_proportion,
+					limit_cores_offered: old.limit_cores_offered,
+					renewal_bump: old.renewal_bump,
+					contribution_timeout: old.contribution_timeout,
+				});
+			}
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok(Configuration::<T>::get().encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "The storage version wasn't set");
+
+			let old = Option::<ConfigRecord<BlockNumberFor<T>, RCBlockNumberOf<T>>>::decode(
+				&mut &state[..],
+			)
+			.map_err(|_| "Failed to decode the configuration")?;
+			let new = crate::Configuration::<T>::get();
+			ensure!(old.is_some() == new.is_some(), "The configuration wasn't migrated");
+			if let (Some(old), Some(new)) = (old, new) {
+				ensure!(
+					old.region_length == new.region_length &&
+						old.interlude_length.saturated_into::<u128>() ==
+							new.interlude_length.saturated_into::<u128>() &&
+						old.leadin_length.saturated_into::<u128>() ==
+							new.leadin_length.saturated_into::<u128>(),
+					"The configuration wasn't migrated"
+				);
+			}
+			Ok(())
+		}
+	}
+}
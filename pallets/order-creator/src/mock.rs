@@ -13,37 +13,53 @@
 // You should have received a copy of the GNU General Public License
 // along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
 
-use crate::ParaId;
+use crate::{
+	broker_storage_key, CoretimeChain, CoretimeConfigRecordOf, CoretimeSaleInfoRecordOf,
+	OrderRequirements, ParaId, SystemCoretimeChain,
+};
 use codec::{Decode, Encode};
+use cumulus_primitives_core::relay_chain::{well_known_keys, HeadData};
 use frame_support::{
 	pallet_prelude::*,
 	parameter_types,
-	traits::Everything,
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision},
+		Everything,
+	},
 	weights::{
-		constants::ExtrinsicBaseWeight, WeightToFeeCoefficient, WeightToFeeCoefficients,
-		WeightToFeePolynomial,
+		constants::ExtrinsicBaseWeight, RuntimeDbWeight, WeightToFeeCoefficient,
+		WeightToFeeCoefficients, WeightToFeePolynomial,
 	},
 };
 use frame_system::EnsureRoot;
+use pallet_broker::Timeslice;
+use scale_info::prelude::collections::BTreeMap;
 use smallvec::smallvec;
 use sp_core::{ConstU64, H256};
 use sp_runtime::{
-	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
-	BuildStorage, Perbill,
+	traits::{BlakeTwo256, BlockNumberProvider, Header as HeaderT, IdentityLookup},
+	BuildStorage, Perbill, StateVersion,
 };
+use sp_state_machine::{prove_read, TrieBackend};
+use sp_trie::StorageProof;
 use xcm::latest::prelude::*;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type AccountId = u64;
 
+pub type CoretimeConfigRecord = CoretimeConfigRecordOf<SystemCoretimeChain>;
+pub type CoretimeSaleInfoRecord = CoretimeSaleInfoRecordOf<SystemCoretimeChain>;
+
 pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
 
 frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances,
-		OrderCreator: crate::{Pallet, Call, Storage, Event<T>}
+		OrderCreator: crate::{Pallet, Call, Storage, Event<T>, Config<T>}
 	}
 );
 
@@ -57,7 +73,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = MockDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 	type Nonce = u64;
@@ -105,7 +121,7 @@ impl WeightToFeePolynomial for WeightToFee {
 		// in Rococo, extrinsic base weight (smallest non-zero weight) is mapped to 1 MILLIUNIT:
 		// in our template, we map to 1/10 of that, or 1/10 MILLIUNIT
 		let p = MILLIUNIT / 10;
-		let q = 100 * u64::from(ExtrinsicBaseWeight::get().ref_time());
+		let q = 100 * ExtrinsicBaseWeight::get().ref_time();
 		smallvec![WeightToFeeCoefficient {
 			degree: 1,
 			negative: false,
@@ -129,8 +145,6 @@ impl BlockNumberProvider for RelayBlockNumberProvider {
 	}
 }
 
-use crate::OrderRequirements;
-
 #[derive(Encode, Decode)]
 enum RegionXRuntimeCalls {
 	#[codec(index = 92)]
@@ -157,13 +171,190 @@ impl crate::CallEncoder for CallEncoder {
 	}
 }
 
+parameter_types! {
+	// When set, the dispatcher will fail with the given error.
+	pub static DispatchFailure: Option<SendError> = None;
+	// The id of the query registered for the next dispatched order.
+	pub static NextQueryId: QueryId = 0;
+	// The requirements of the successfully dispatched orders, in the order of their dispatch.
+	pub static DispatchedOrders: Vec<OrderRequirements> = vec![];
+}
+
 pub struct DummyOrderDispatcher;
 impl crate::OrderDispatcher for DummyOrderDispatcher {
-	fn dispatch(_requirements: OrderRequirements) -> DispatchResult {
+	fn dispatch(requirements: OrderRequirements) -> Result<Option<QueryId>, SendError> {
+		match DispatchFailure::get() {
+			Some(error) => Err(error),
+			None => {
+				DispatchedOrders::mutate(|orders| orders.push(requirements));
+				let query_id = NextQueryId::get();
+				NextQueryId::set(query_id + 1);
+				Ok(Some(query_id))
+			},
+		}
+	}
+
+	// Only a stand-in for the message of the `DefaultOrderDispatcher`. The preview of the real
+	// message is checked against the message it sends in `xcm_tests`.
+	fn order_message(
+		requirements: OrderRequirements,
+	) -> Result<(MultiLocation, Xcm<()>), SendError> {
+		match DispatchFailure::get() {
+			Some(error) => Err(error),
+			None => Ok((
+				RegionXLocation::get(),
+				Xcm(vec![Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					require_weight_at_most: crate::OrderCallWeight::<Test>::get(),
+					call: <CallEncoder as crate::CallEncoder>::order_creation_call(requirements)
+						.into(),
+				}]),
+			)),
+		}
+	}
+}
+
+/// Treats signed origins as responses from the parachain with the same id as the account.
+pub struct EnsureParachainResponse;
+impl EnsureOrigin<RuntimeOrigin> for EnsureParachainResponse {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::<AccountId>::try_origin(o)?;
+		Ok(MultiLocation { parents: 1, interior: X1(Parachain(who as u32)) })
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(2000))
+	}
+}
+
+parameter_types! {
+	pub static FundedFees: Vec<(AccountId, u64)> = vec![];
+}
+
+pub struct DummyFeeFunder;
+impl crate::FeeFunder<AccountId, u64> for DummyFeeFunder {
+	fn fund(source: &AccountId, amount: u64) -> DispatchResult {
+		// The tokens leave the parachain.
+		<Balances as Mutate<AccountId>>::burn_from(
+			source,
+			amount,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		FundedFees::mutate(|fees| fees.push((*source, amount)));
 		Ok(())
 	}
 }
 
+parameter_types! {
+	pub static CoreUsage: Option<Perbill> = None;
+	// By default the due orders are sent in the same block.
+	pub static MaxDispatchDelay: u64 = 0;
+	// The relay chain state root against which the Coretime chain state proofs are verified.
+	pub static RelayStateRoot: Option<H256> = None;
+	pub const CoretimeParaId: ParaId = ParaId::new(1005);
+}
+
+pub struct DummyUsageProvider;
+impl crate::UsageProvider for DummyUsageProvider {
+	fn core_usage() -> Option<Perbill> {
+		CoreUsage::get()
+	}
+}
+
+pub struct DummyRelayStateRootProvider;
+impl crate::RelayStateRootProvider for DummyRelayStateRootProvider {
+	fn relay_state_root() -> Option<H256> {
+		RelayStateRoot::get()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper for BenchmarkHelper {
+	fn coretime_state_proofs() -> (StorageProof, StorageProof) {
+		let configuration = CoretimeConfigRecord {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		};
+		let (relay_root, relay_proof, coretime_proof) =
+			coretime_state_proofs(Some(configuration), Some(coretime_sale_info(1_260)));
+		RelayStateRoot::set(Some(relay_root));
+		(relay_proof, coretime_proof)
+	}
+}
+
+parameter_types! {
+	pub const MockDbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1_000, write: 10_000 };
+}
+
+/// Uses distinct weights for the work done in `on_initialize`, so that the weight reported by the
+/// hook can be checked.
+pub struct MockWeightInfo;
+impl crate::WeightInfo for MockWeightInfo {
+	fn set_configuration() -> Weight {
+		Default::default()
+	}
+	fn schedule_next_order() -> Weight {
+		Default::default()
+	}
+	fn set_requirement_profile() -> Weight {
+		Default::default()
+	}
+	fn set_fee_funding() -> Weight {
+		Default::default()
+	}
+	fn set_order_call_weight() -> Weight {
+		Default::default()
+	}
+	fn notify_order_status() -> Weight {
+		Default::default()
+	}
+	fn schedule_requirements_change() -> Weight {
+		Default::default()
+	}
+	fn cancel_requirements_change() -> Weight {
+		Default::default()
+	}
+	fn preview_order_messages(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn pause() -> Weight {
+		Default::default()
+	}
+	fn force_order(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn sync_coretime_state(_n: u32) -> Weight {
+		Default::default()
+	}
+	fn resume() -> Weight {
+		Weight::from_parts(100_000, 0)
+	}
+	fn on_initialize_idle() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+	fn on_initialize_order(n: u32) -> Weight {
+		Weight::from_parts(10_000_000, 0).saturating_mul(n.into())
+	}
+	fn send_queued_order() -> Weight {
+		Weight::from_parts(1_000_000_000, 0)
+	}
+	fn dispatch() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+}
+
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RelaychainCurrency = Balances;
@@ -172,10 +363,28 @@ impl crate::Config for Test {
 	type RegionXLocation = RegionXLocation;
 	type AdminOrigin = EnsureRoot<<Test as frame_system::Config>::AccountId>;
 	type OrderDispatcher = DummyOrderDispatcher;
+	type XcmResponseOrigin = EnsureParachainResponse;
+	type FeeFunder = DummyFeeFunder;
+	type UsageProvider = DummyUsageProvider;
+	type CoretimeParaId = CoretimeParaId;
+	type RelayStateRootProvider = DummyRelayStateRootProvider;
+	type CoretimeChain = SystemCoretimeChain;
+	type MaxStateProofSize = ConstU32<4_096>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
 	type CallEncoder = CallEncoder;
 	type WeightToFee = WeightToFee;
 	type TimeslicePeriod = ConstU64<80>;
-	type WeightInfo = crate::TestWeightInfo;
+	type CoretimeBlockPeriod = ConstU64<2>;
+	type MaxDispatchDelay = MaxDispatchDelay;
+	type MaxRetries = ConstU32<3>;
+	type RetryDelay = ConstU64<10>;
+	type MaxOrderHistory = ConstU32<3>;
+	type MaxRegionSplit = ConstU32<4>;
+	type MaxProfiles = ConstU32<2>;
+	type MaxScheduledChanges = ConstU32<2>;
+	type ResponseTimeout = ConstU64<100>;
+	type WeightInfo = MockWeightInfo;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -185,3 +394,53 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
+
+/// Generates the relay chain and Coretime chain state proofs of the Coretime chain state, together
+/// with the relay chain state root they are proved against.
+pub fn coretime_state_proofs(
+	configuration: Option<CoretimeConfigRecord>,
+	sale_info: Option<CoretimeSaleInfoRecord>,
+) -> (H256, StorageProof, StorageProof) {
+	let configuration_key = broker_storage_key::<SystemCoretimeChain>(b"Configuration");
+	let sale_info_key = broker_storage_key::<SystemCoretimeChain>(b"SaleInfo");
+	let coretime_state: BTreeMap<_, _> = [
+		configuration.map(|value| (configuration_key.clone(), value.encode())),
+		sale_info.map(|value| (sale_info_key.clone(), value.encode())),
+	]
+	.into_iter()
+	.flatten()
+	.collect();
+	let coretime_backend = TrieBackend::<_, BlakeTwo256>::from((coretime_state, StateVersion::V1));
+	let coretime_root = *coretime_backend.root();
+	let coretime_proof = prove_read(coretime_backend, [configuration_key, sale_info_key]).unwrap();
+
+	let head = <SystemCoretimeChain as CoretimeChain>::Header::new(
+		1,
+		Default::default(),
+		coretime_root,
+		Default::default(),
+		Default::default(),
+	);
+	let head_key = well_known_keys::para_head(CoretimeParaId::get());
+	let relay_state = BTreeMap::from([(head_key.clone(), HeadData(head.encode()).encode())]);
+	let relay_backend = TrieBackend::<_, BlakeTwo256>::from((relay_state, StateVersion::V1));
+	let relay_root = *relay_backend.root();
+	let relay_proof = prove_read(relay_backend, [head_key]).unwrap();
+
+	(relay_root, relay_proof, coretime_proof)
+}
+
+pub fn coretime_sale_info(region_begin: Timeslice) -> CoretimeSaleInfoRecord {
+	CoretimeSaleInfoRecord {
+		sale_start: 0,
+		leadin_length: 21_600,
+		price: 100,
+		region_begin,
+		region_end: region_begin + 1_260,
+		ideal_cores_sold: 1,
+		cores_offered: 2,
+		first_core: 0,
+		sellout_price: None,
+		cores_sold: 0,
+	}
+}
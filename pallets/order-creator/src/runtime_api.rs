@@ -0,0 +1,35 @@
+//! Runtime API definition for the order creator pallet.
+
+use crate::{OrderRequirements, ProfileName};
+use codec::Codec;
+use pallet_broker::Timeslice;
+use scale_info::prelude::vec::Vec;
+use sp_runtime::DispatchError;
+use xcm::{latest::MultiLocation, VersionedXcm};
+
+sp_api::decl_runtime_apis! {
+	/// Exposes the state of the order creator so that off-chain tooling doesn't have to
+	/// reimplement the region arithmetic of the pallet.
+	pub trait OrderCreatorApi<Balance>
+	where
+		Balance: Codec,
+	{
+		/// The current timeslice on the Coretime chain, as seen by the pallet.
+		fn current_timeslice() -> Timeslice;
+
+		/// The timeslice at which the next orders will be made, if scheduled.
+		fn next_order() -> Option<Timeslice>;
+
+		/// The orders which will be dispatched next, together with the profile each of them is
+		/// made for.
+		fn next_orders() -> Vec<(ProfileName, OrderRequirements)>;
+
+		/// The estimated fee paid for the execution of a single order on the RegionX parachain.
+		fn order_fee() -> Balance;
+
+		/// The exact messages which will be sent to the RegionX parachain for the next orders,
+		/// together with their destination and the profile each of them is made for.
+		fn next_order_messages(
+		) -> Result<Vec<(ProfileName, MultiLocation, VersionedXcm<()>)>, DispatchError>;
+	}
+}
@@ -0,0 +1,199 @@
+use crate::ParaId;
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::fmt::Debug;
+use cumulus_primitives_core::relay_chain::{self, well_known_keys, HeadData};
+use frame_support::{CloneNoBound, DebugNoBound, EqNoBound, Parameter, PartialEqNoBound};
+use pallet_broker::{ConfigRecord, SaleInfoRecord};
+use scale_info::prelude::vec::Vec;
+use sp_core::{twox_128, H256};
+use sp_runtime::{
+	generic::Header,
+	traits::{
+		AtLeast32BitUnsigned, BlakeTwo256, Header as HeaderT, MaybeSerializeDeserialize, Member,
+	},
+	SaturatedConversion,
+};
+use sp_state_machine::{Backend, TrieBackendBuilder};
+use sp_trie::{HashDBT, MemoryDB, StorageProof, EMPTY_PREFIX};
+
+/// The types with which the state of the Coretime chain is laid out.
+///
+/// Used for decoding the proved state of the Coretime chain, which is independent of the types
+/// used by the parachain itself.
+pub trait CoretimeChain {
+	/// The block number of the Coretime chain.
+	///
+	/// The lengths of the sale periods are expressed in Coretime chain blocks.
+	type BlockNumber: Parameter
+		+ Member
+		+ MaxEncodedLen
+		+ AtLeast32BitUnsigned
+		+ Copy
+		+ MaybeSerializeDeserialize;
+	/// The relay chain block number, as known to the Coretime chain.
+	type RelayBlockNumber: Decode + Debug + Clone + Eq + AtLeast32BitUnsigned;
+	/// The balance in which the sales of the Coretime chain are priced.
+	type Balance: Decode + Debug + Clone + Eq;
+	/// The header of the Coretime chain blocks, as stored in the parachain heads of the relay
+	/// chain.
+	type Header: HeaderT<Hash = H256, Hashing = BlakeTwo256>;
+	/// The name under which `pallet_broker` is deployed on the Coretime chain.
+	const BROKER_PALLET_NAME: &'static [u8];
+}
+
+/// The Coretime chain of Polkadot and Kusama.
+pub struct SystemCoretimeChain;
+impl CoretimeChain for SystemCoretimeChain {
+	type BlockNumber = u32;
+	type RelayBlockNumber = u32;
+	type Balance = relay_chain::Balance;
+	type Header = Header<u32, BlakeTwo256>;
+	const BROKER_PALLET_NAME: &'static [u8] = b"Broker";
+}
+
+/// The configuration of the Coretime chain, as stored by `pallet_broker`.
+pub type CoretimeConfigRecordOf<C> =
+	ConfigRecord<<C as CoretimeChain>::BlockNumber, <C as CoretimeChain>::RelayBlockNumber>;
+
+/// The ongoing sale on the Coretime chain, as stored by `pallet_broker`.
+pub type CoretimeSaleInfoRecordOf<C> =
+	SaleInfoRecord<<C as CoretimeChain>::Balance, <C as CoretimeChain>::BlockNumber>;
+
+/// Type providing the state root of the latest relay chain block known to the parachain.
+///
+/// On a parachain this is the `relay_parent_storage_root` of the validation data provided by
+/// `cumulus_pallet_parachain_system`.
+pub trait RelayStateRootProvider {
+	/// The state root of the latest known relay chain block, if any.
+	fn relay_state_root() -> Option<H256>;
+}
+
+impl RelayStateRootProvider for () {
+	fn relay_state_root() -> Option<H256> {
+		None
+	}
+}
+
+/// Type providing valid state proofs of the Coretime chain for benchmarking.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Returns the relay chain and Coretime chain state proofs of the Coretime chain state.
+	///
+	/// The proofs have to be valid against the state root returned by the
+	/// `RelayStateRootProvider`, which may be set up for this purpose.
+	fn coretime_state_proofs() -> (StorageProof, StorageProof);
+}
+
+/// The reason for which a state proof couldn't be verified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateProofError {
+	/// The proof doesn't prove the requested values against the expected state root.
+	InvalidProof,
+	/// A proved value couldn't be decoded.
+	InvalidValue,
+	/// The proof shows that a value doesn't exist.
+	MissingValue,
+}
+
+/// The state of the Coretime chain which is relevant for making orders.
+#[derive(DebugNoBound, CloneNoBound, PartialEqNoBound, EqNoBound)]
+pub struct CoretimeState<C: CoretimeChain> {
+	/// The configuration of the Coretime chain.
+	pub configuration: CoretimeConfigRecordOf<C>,
+	/// The ongoing sale on the Coretime chain.
+	pub sale_info: CoretimeSaleInfoRecordOf<C>,
+}
+
+impl<C: CoretimeChain> CoretimeState<C> {
+	/// Converts the proved Coretime chain configuration into the `ConfigRecord` kept in storage.
+	///
+	/// The lengths of the sale periods are kept in Coretime chain blocks, only the advance notice
+	/// is converted to the relay chain block number of the parachain.
+	pub fn config_record<RelayBlockNumber: AtLeast32BitUnsigned>(
+		&self,
+	) -> ConfigRecord<C::BlockNumber, RelayBlockNumber> {
+		let configuration = &self.configuration;
+		ConfigRecord {
+			advance_notice: configuration
+				.advance_notice
+				.clone()
+				.saturated_into::<u128>()
+				.saturated_into(),
+			interlude_length: configuration.interlude_length,
+			leadin_length: configuration.leadin_length,
+			region_length: configuration.region_length,
+			ideal_bulk_proportion: configuration.ideal_bulk_proportion,
+			limit_cores_offered: configuration.limit_cores_offered,
+			renewal_bump: configuration.renewal_bump,
+			contribution_timeout: configuration.contribution_timeout,
+		}
+	}
+}
+
+/// Returns the storage key of a `pallet_broker` storage value on the Coretime chain.
+pub fn broker_storage_key<C: CoretimeChain>(storage_name: &[u8]) -> Vec<u8> {
+	[twox_128(C::BROKER_PALLET_NAME), twox_128(storage_name)].concat()
+}
+
+/// Returns the combined size in bytes of the state proofs.
+pub fn state_proofs_size(relay_proof: &StorageProof, coretime_proof: &StorageProof) -> u32 {
+	relay_proof
+		.encoded_size()
+		.saturating_add(coretime_proof.encoded_size())
+		.saturated_into()
+}
+
+/// Verifies the state of the Coretime chain against the state root of a relay chain block.
+///
+/// `relay_proof` has to prove the head of the Coretime chain against `relay_state_root`, while
+/// `coretime_proof` has to prove the `Configuration` and `SaleInfo` of `pallet_broker` against the
+/// state root contained in that head.
+pub fn verify_coretime_state<C: CoretimeChain>(
+	relay_state_root: H256,
+	coretime_para_id: ParaId,
+	relay_proof: StorageProof,
+	coretime_proof: StorageProof,
+) -> Result<CoretimeState<C>, StateProofError> {
+	let relay_db = proof_db(relay_state_root, relay_proof)?;
+	let head: HeadData =
+		read_value(relay_db, relay_state_root, &well_known_keys::para_head(coretime_para_id))?;
+	let header = C::Header::decode(&mut &head.0[..]).map_err(|_| StateProofError::InvalidValue)?;
+
+	let coretime_state_root = *header.state_root();
+	let coretime_db = proof_db(coretime_state_root, coretime_proof)?;
+	let configuration = read_value(
+		coretime_db.clone(),
+		coretime_state_root,
+		&broker_storage_key::<C>(b"Configuration"),
+	)?;
+	let sale_info =
+		read_value(coretime_db, coretime_state_root, &broker_storage_key::<C>(b"SaleInfo"))?;
+
+	Ok(CoretimeState { configuration, sale_info })
+}
+
+/// Builds the database of the trie nodes contained in the proof.
+fn proof_db(
+	state_root: H256,
+	proof: StorageProof,
+) -> Result<MemoryDB<BlakeTwo256>, StateProofError> {
+	let db = proof.into_memory_db::<BlakeTwo256>();
+	if !db.contains(&state_root, EMPTY_PREFIX) {
+		return Err(StateProofError::InvalidProof);
+	}
+	Ok(db)
+}
+
+/// Reads and decodes a proved value.
+fn read_value<V: Decode>(
+	db: MemoryDB<BlakeTwo256>,
+	state_root: H256,
+	key: &[u8],
+) -> Result<V, StateProofError> {
+	let backend = TrieBackendBuilder::new(db, state_root).build();
+	let value = backend
+		.storage(key)
+		.map_err(|_| StateProofError::InvalidProof)?
+		.ok_or(StateProofError::MissingValue)?;
+	V::decode(&mut &value[..]).map_err(|_| StateProofError::InvalidValue)
+}
@@ -13,13 +13,32 @@
 // You should have received a copy of the GNU General Public License
 // along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::{assert_noop, assert_ok};
-use sp_core::Get;
-use sp_runtime::{traits::BadOrigin, Perbill};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Pays,
+	pallet_prelude::Weight,
+	traits::{
+		fungible::{Inspect, Mutate},
+		GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion,
+	},
+};
+use proptest::{collection::vec, prelude::*};
+use sp_core::{Get, H256};
+use sp_runtime::{traits::BadOrigin, BuildStorage, FixedU128, Perbill};
+use sp_trie::StorageProof;
+use xcm::{
+	latest::{Error as XcmError, Instruction, OriginKind, Response, SendError, Xcm},
+	VersionedXcm,
+};
 
 use crate::{
-	mock::*, Config, ConfigRecordOf, Configuration, CoretimeRequirements, Event,
-	GenericRequirements, NextOrder,
+	migration, mock::*, Config, ConfigRecordOf, Configuration, Error, Event, FeeFunding,
+	FeeFundingConfig, FeeMultiplier, GenericRequirements, GenesisConfigRecord, NextOrder,
+	NextOrderId, OccupancyBounds, OldestOrderId, OrderCallWeight, OrderQueries, OrderRecord,
+	OrderRequirements, OrderStatus, Orders, PauseRecord, Paused, PendingRetries, PendingRetriesDue,
+	ProfileName, ProfileRecord, QueryExpiries, QueuedOrders, QueuedOrdersDue, RegionAlignment,
+	RequirementProfile, RequirementProfiles, RetryRecord, ScheduledChanges, WeightInfo, CORE_PARTS,
+	DEFAULT_ORDER_CALL_WEIGHT,
 };
 
 #[test]
@@ -43,6 +62,24 @@ fn set_configuration_works() {
 			BadOrigin
 		);
 
+		// Failure: Zero region length
+		assert_noop!(
+			OrderCreator::set_configuration(
+				RuntimeOrigin::root(),
+				ConfigRecordOf::<Test> { region_length: 0, ..configuration.clone() }
+			),
+			Error::<Test>::InvalidRegionLength
+		);
+
+		// Failure: Zero lead-in length
+		assert_noop!(
+			OrderCreator::set_configuration(
+				RuntimeOrigin::root(),
+				ConfigRecordOf::<Test> { leadin_length: 0, ..configuration.clone() }
+			),
+			Error::<Test>::InvalidLeadinLength
+		);
+
 		// Should be working fine
 		assert_ok!(OrderCreator::set_configuration(RuntimeOrigin::root(), configuration.clone()));
 
@@ -73,30 +110,219 @@ fn schedule_next_order_works() {
 	});
 }
 
+fn profile_name(name: &[u8]) -> ProfileName {
+	ProfileName::truncate_from(name.to_vec())
+}
+
+fn profile(requirements: GenericRequirements) -> RequirementProfile {
+	RequirementProfile { requirements, cadence: 1, active: true }
+}
+
 #[test]
-fn set_coretime_requirements_works() {
+fn set_requirement_profile_works() {
 	new_test_ext().execute_with(|| {
-		assert!(CoretimeRequirements::<Test>::get().is_none());
+		assert!(RequirementProfiles::<Test>::get().is_empty());
+		let main = profile_name(b"main");
 
 		// Failure: Bad Origin
 		assert_noop!(
-			OrderCreator::set_coretime_requirements(RuntimeOrigin::signed(ALICE), None),
+			OrderCreator::set_requirement_profile(RuntimeOrigin::signed(ALICE), main.clone(), None),
 			BadOrigin
 		);
 
-		let requirements = Some(GenericRequirements { core_occupancy: 28_800 }); // 50%
+		// Failure: Invalid core occupancy
+		for core_occupancy in [0, CORE_PARTS + 1] {
+			assert_noop!(
+				OrderCreator::set_requirement_profile(
+					RuntimeOrigin::root(),
+					main.clone(),
+					Some(profile(GenericRequirements::new(core_occupancy)))
+				),
+				Error::<Test>::InvalidCoreOccupancy
+			);
+		}
 
-		// Should work
-		assert_ok!(OrderCreator::set_coretime_requirements(
+		// Failure: Zero duration
+		assert_noop!(
+			OrderCreator::set_requirement_profile(
+				RuntimeOrigin::root(),
+				main.clone(),
+				Some(profile(GenericRequirements {
+					duration: Some(0),
+					..GenericRequirements::new(28_800)
+				}))
+			),
+			Error::<Test>::InvalidRegionDuration
+		);
+
+		// Failure: Invalid split
+		let max_split: u32 = <Test as Config>::MaxRegionSplit::get();
+		for split in [0, max_split + 1] {
+			assert_noop!(
+				OrderCreator::set_requirement_profile(
+					RuntimeOrigin::root(),
+					main.clone(),
+					Some(profile(GenericRequirements {
+						split,
+						..GenericRequirements::new(28_800)
+					}))
+				),
+				Error::<Test>::InvalidRegionSplit
+			);
+		}
+
+		// Failure: Zero cadence
+		assert_noop!(
+			OrderCreator::set_requirement_profile(
+				RuntimeOrigin::root(),
+				main.clone(),
+				Some(RequirementProfile {
+					cadence: 0,
+					..profile(GenericRequirements::new(28_800))
+				})
+			),
+			Error::<Test>::InvalidCadence
+		);
+
+		// The entire core can be required.
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			main.clone(),
+			Some(profile(GenericRequirements::new(CORE_PARTS)))
+		));
+
+		// New profiles make their first orders at `NextOrder`.
+		assert_ok!(OrderCreator::schedule_next_order(RuntimeOrigin::root(), 1_260));
+		let batch = profile(GenericRequirements::new(14_400));
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			profile_name(b"batch"),
+			Some(batch.clone())
+		));
+		assert_eq!(
+			RequirementProfiles::<Test>::get().get(&profile_name(b"batch")),
+			Some(&ProfileRecord { profile: batch, next_order: 1_260 })
+		);
+
+		// Failure: Too many profiles
+		assert_noop!(
+			OrderCreator::set_requirement_profile(
+				RuntimeOrigin::root(),
+				profile_name(b"other"),
+				Some(profile(GenericRequirements::new(28_800)))
+			),
+			Error::<Test>::TooManyProfiles
+		);
+
+		// Updating a profile keeps its schedule.
+		let updated = Some(profile(GenericRequirements::new(28_800))); // 50%
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			main.clone(),
+			updated.clone()
+		));
+		assert_eq!(
+			RequirementProfiles::<Test>::get().get(&main),
+			Some(&ProfileRecord { profile: updated.clone().unwrap(), next_order: 0 })
+		);
+		System::assert_last_event(
+			Event::RequirementProfileSet { name: main.clone(), profile: updated }.into(),
+		);
+
+		// Profiles can be removed.
+		assert_ok!(OrderCreator::set_requirement_profile(
 			RuntimeOrigin::root(),
-			requirements.clone()
+			main.clone(),
+			None
 		));
+		assert!(RequirementProfiles::<Test>::get().get(&main).is_none());
+		System::assert_last_event(
+			Event::RequirementProfileSet { name: main, profile: None }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_fee_funding_works() {
+	new_test_ext().execute_with(|| {
+		assert!(FeeFunding::<Test>::get().is_none());
+
+		let funding = Some(FeeFundingConfig { source: BOB, min_balance: 1_000 });
+
+		// Failure: Bad Origin
+		assert_noop!(
+			OrderCreator::set_fee_funding(RuntimeOrigin::signed(ALICE), funding.clone()),
+			BadOrigin
+		);
+
+		// Should work
+		assert_ok!(OrderCreator::set_fee_funding(RuntimeOrigin::root(), funding.clone()));
 
 		// Check the storage item
-		assert_eq!(CoretimeRequirements::<Test>::get(), requirements.clone());
+		assert_eq!(FeeFunding::<Test>::get(), funding.clone());
+
+		// Check the emitted events
+		System::assert_last_event(Event::FeeFundingSet { funding }.into())
+	});
+}
+
+#[test]
+fn set_order_call_weight_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(OrderCallWeight::<Test>::get(), DEFAULT_ORDER_CALL_WEIGHT);
+		assert_eq!(FeeMultiplier::<Test>::get(), FixedU128::from_u32(1));
+
+		let call_weight = Weight::from_parts(200_000_000, 20_000);
+		let fee_multiplier = FixedU128::from_rational(3, 2);
+
+		// Failure: Bad Origin
+		assert_noop!(
+			OrderCreator::set_order_call_weight(
+				RuntimeOrigin::signed(ALICE),
+				call_weight,
+				fee_multiplier
+			),
+			BadOrigin
+		);
+
+		// Failure: Zero weight
+		assert_noop!(
+			OrderCreator::set_order_call_weight(
+				RuntimeOrigin::root(),
+				Weight::zero(),
+				fee_multiplier
+			),
+			Error::<Test>::InvalidCallWeight
+		);
+
+		// Failure: Zero multiplier
+		assert_noop!(
+			OrderCreator::set_order_call_weight(
+				RuntimeOrigin::root(),
+				call_weight,
+				FixedU128::from_u32(0)
+			),
+			Error::<Test>::InvalidFeeMultiplier
+		);
+
+		let fee = OrderCreator::order_fee();
+
+		// Should work
+		assert_ok!(OrderCreator::set_order_call_weight(
+			RuntimeOrigin::root(),
+			call_weight,
+			fee_multiplier
+		));
+
+		// Check the storage items
+		assert_eq!(OrderCallWeight::<Test>::get(), call_weight);
+		assert_eq!(FeeMultiplier::<Test>::get(), fee_multiplier);
+
+		// The fee is based on the new weight and multiplier.
+		assert_eq!(OrderCreator::order_fee(), fee * 2 * 3 / 2);
 
 		// Check the emitted events
-		System::assert_last_event(Event::CoretimeRequirementSet { requirements }.into())
+		System::assert_last_event(Event::OrderCallWeightSet { call_weight, fee_multiplier }.into())
 	});
 }
 
@@ -115,3 +341,1615 @@ fn current_timeslice_works() {
 		assert_eq!(OrderCreator::current_timeslice(), 5);
 	});
 }
+
+fn setup_order_creation() {
+	let configuration = ConfigRecordOf::<Test> {
+		advance_notice: 10,
+		interlude_length: 7_200,
+		leadin_length: 21_600,
+		region_length: 1_260,
+		ideal_bulk_proportion: Perbill::from_percent(40),
+		limit_cores_offered: None,
+		renewal_bump: Perbill::from_percent(40),
+		contribution_timeout: 1_260,
+	};
+	assert_ok!(OrderCreator::set_configuration(RuntimeOrigin::root(), configuration));
+	assert_ok!(OrderCreator::schedule_next_order(RuntimeOrigin::root(), 1_260));
+	assert_ok!(OrderCreator::set_requirement_profile(
+		RuntimeOrigin::root(),
+		profile_name(b"main"),
+		Some(profile(GenericRequirements::new(28_800)))
+	));
+
+	let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+	RelayBlockNumber::set(1_260 * timeslice_period);
+}
+
+#[test]
+fn failed_order_dispatch_is_reported() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		DispatchFailure::set(Some(SendError::Unroutable));
+
+		OrderCreator::on_initialize(1);
+		System::assert_has_event(
+			Event::OrderDispatchFailed { order_id: 0, error: SendError::Unroutable }.into(),
+		);
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Failed));
+
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		let retry = RetryRecord { requirements, attempts: 1, next_attempt: 1, deadline: 1_980 };
+		assert_eq!(
+			OrderCreator::dispatch_order(1, 0, &retry),
+			Err(Error::<Test>::Unreachable.into())
+		);
+	});
+}
+
+#[test]
+fn failed_order_dispatch_is_retried() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		DispatchFailure::set(Some(SendError::Transport("")));
+
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		OrderCreator::on_initialize(1);
+		System::assert_last_event(
+			Event::OrderRetryScheduled { order_id: 0, attempt: 1, at: 11 }.into(),
+		);
+		// The failed order is handled by the retry schedule from now on.
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+		assert_eq!(
+			PendingRetries::<Test>::get(0),
+			Some(RetryRecord {
+				requirements: requirements.clone(),
+				attempts: 1,
+				next_attempt: 11,
+				deadline: 1_980,
+			})
+		);
+
+		// Nothing happens before the retry is due.
+		System::reset_events();
+		OrderCreator::on_initialize(10);
+		assert!(System::events().is_empty());
+
+		// The delay doubles with each failed attempt.
+		OrderCreator::on_initialize(11);
+		System::assert_last_event(
+			Event::OrderRetryScheduled { order_id: 0, attempt: 2, at: 31 }.into(),
+		);
+
+		// Once the dispatch succeeds the order is no longer pending.
+		DispatchFailure::set(None);
+		OrderCreator::on_initialize(31);
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		assert_eq!(
+			Orders::<Test>::get(0),
+			Some(OrderRecord {
+				profile: profile_name(b"main"),
+				requirements,
+				dispatched_at: 31,
+				fee: OrderCreator::order_fee(),
+				status: OrderStatus::Dispatched,
+				query_id: Some(0),
+			})
+		);
+	});
+}
+
+#[test]
+fn due_retries_are_queued() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		DispatchFailure::set(Some(SendError::Transport("")));
+		OrderCreator::on_initialize(1);
+		assert_eq!(PendingRetriesDue::<Test>::get(), Some(11));
+
+		// The pending retries are only read once one of them is due.
+		MaxDispatchDelay::set(5);
+		DispatchFailure::set(None);
+		OrderCreator::on_initialize(10);
+		assert!(PendingRetries::<Test>::get(0).is_some());
+
+		// The due retry waits for spare block weight like any other order.
+		OrderCreator::on_initialize(11);
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		assert_eq!(PendingRetriesDue::<Test>::get(), None);
+		assert_eq!(QueuedOrders::<Test>::get(0).map(|order| order.attempts), Some(2));
+		assert_eq!(QueuedOrdersDue::<Test>::get(), Some(16));
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Failed));
+
+		let order_weight = MockWeightInfo::send_queued_order() +
+			<Test as frame_system::Config>::DbWeight::get().reads(1);
+		OrderCreator::on_idle(11, order_weight * 2);
+		assert!(QueuedOrders::<Test>::get(0).is_none());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Dispatched));
+	});
+}
+
+#[test]
+fn failed_order_gets_abandoned() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		DispatchFailure::set(Some(SendError::Fees));
+
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		OrderCreator::on_initialize(1);
+		OrderCreator::on_initialize(11);
+		OrderCreator::on_initialize(31);
+		assert!(PendingRetries::<Test>::get(0).is_some());
+
+		// The retry limit is reached.
+		OrderCreator::on_initialize(71);
+		System::assert_last_event(Event::OrderAbandoned { order_id: 0 }.into());
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Expired));
+
+		// The order is abandoned once the region starts.
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		PendingRetries::<Test>::insert(
+			0,
+			RetryRecord {
+				requirements: requirements.clone(),
+				attempts: 1,
+				next_attempt: 80,
+				deadline: 1_980,
+			},
+		);
+		PendingRetriesDue::<Test>::put(80);
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(80);
+		System::assert_has_event(Event::OrderAbandoned { order_id: 0 }.into());
+		assert!(PendingRetries::<Test>::get(0).is_none());
+
+		// The order is abandoned once its deadline is reached, even before the region starts.
+		System::reset_events();
+		PendingRetries::<Test>::insert(
+			0,
+			RetryRecord { requirements, attempts: 1, next_attempt: 90, deadline: 1_980 },
+		);
+		PendingRetriesDue::<Test>::put(90);
+		RelayBlockNumber::set(1_980 * timeslice_period);
+		OrderCreator::on_initialize(90);
+		System::assert_has_event(Event::OrderAbandoned { order_id: 0 }.into());
+		assert!(PendingRetries::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn orders_are_recorded() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+
+		OrderCreator::on_initialize(1);
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		System::assert_has_event(
+			Event::OrderCreated {
+				order_id: 0,
+				profile: profile_name(b"main"),
+				requirements: requirements.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Orders::<Test>::get(0),
+			Some(OrderRecord {
+				profile: profile_name(b"main"),
+				requirements,
+				dispatched_at: 1,
+				fee: OrderCreator::order_fee(),
+				status: OrderStatus::Dispatched,
+				query_id: Some(0),
+			})
+		);
+		assert_eq!(NextOrderId::<Test>::get(), 1);
+
+		// Orders whose outcome is still unknown are kept beyond `MaxOrderHistory`.
+		for (block, order_timeslice) in [(2, 2_520), (3, 3_780), (4, 5_040)] {
+			RelayBlockNumber::set(order_timeslice * timeslice_period);
+			OrderCreator::on_initialize(block);
+		}
+		assert_eq!(NextOrderId::<Test>::get(), 4);
+		assert!(Orders::<Test>::get(0).is_some());
+		assert_eq!(
+			Orders::<Test>::get(3).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 6_300, end: 7_560, core_occupancy: 28_800 })
+		);
+
+		// Once their outcome is known, only the last `MaxOrderHistory` orders are kept.
+		for query_id in 0..2 {
+			assert_ok!(OrderCreator::notify_order_status(
+				RuntimeOrigin::signed(2000),
+				query_id,
+				Response::ExecutionResult(None)
+			));
+		}
+		RelayBlockNumber::set(6_300 * timeslice_period);
+		OrderCreator::on_initialize(5);
+		assert_eq!(NextOrderId::<Test>::get(), 5);
+		assert!(Orders::<Test>::get(0).is_none());
+		assert!(Orders::<Test>::get(1).is_none());
+		assert!(Orders::<Test>::get(2).is_some());
+		assert_eq!(OldestOrderId::<Test>::get(), 2);
+	});
+}
+
+#[test]
+fn missed_orders_are_skipped() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+
+		// The bulk periods starting at 1260 and 2520 were missed.
+		RelayBlockNumber::set(5_000 * timeslice_period);
+		OrderCreator::on_initialize(1);
+		System::assert_has_event(Event::MissedOrdersSkipped { from: 1_260, count: 2 }.into());
+
+		// The order is made for the region following the current bulk period.
+		let requirements = OrderRequirements { begin: 5_040, end: 6_300, core_occupancy: 28_800 };
+		System::assert_has_event(
+			Event::OrderCreated { order_id: 0, profile: profile_name(b"main"), requirements }
+				.into(),
+		);
+		assert_eq!(NextOrder::<Test>::get(), Some(5_040));
+
+		// No stale orders are made in the following blocks.
+		OrderCreator::on_initialize(2);
+		assert_eq!(NextOrderId::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn orders_follow_the_bulk_periods() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let order = |begin| OrderRequirements { begin, end: begin + 1_260, core_occupancy: 28_800 };
+
+		// Nothing is ordered before `NextOrder`.
+		RelayBlockNumber::set(1_260 * timeslice_period - 1);
+		OrderCreator::on_initialize(1);
+		assert!(DispatchedOrders::get().is_empty());
+		assert_eq!(NextOrder::<Test>::get(), Some(1_260));
+
+		// At the start of the bulk period the region of the next bulk period is ordered.
+		RelayBlockNumber::set(1_260 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		assert_eq!(DispatchedOrders::take(), vec![order(2_520)]);
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+
+		// No other orders are made during the bulk period.
+		for (block, timeslice) in [(3, 1_261), (4, 2_000), (5, 2_519)] {
+			RelayBlockNumber::set(timeslice * timeslice_period);
+			OrderCreator::on_initialize(block);
+		}
+		assert!(DispatchedOrders::get().is_empty());
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+
+		// The order is made even if the start of the bulk period is not hit exactly.
+		RelayBlockNumber::set(2_600 * timeslice_period + 5);
+		OrderCreator::on_initialize(6);
+		assert_eq!(DispatchedOrders::take(), vec![order(3_780)]);
+		assert_eq!(NextOrder::<Test>::get(), Some(3_780));
+	});
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(64))]
+
+	/// Drives the order creation through relay chain blocks produced at arbitrary intervals.
+	#[test]
+	fn orders_are_made_once_per_bulk_period(
+		region_length in 1..2_000u32,
+		first_order in 0..5_000u32,
+		start in 0..500_000u64,
+		intervals in vec(0..200_000u64, 1..50),
+	) {
+		new_test_ext().execute_with(|| {
+			let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+			let configuration = ConfigRecordOf::<Test> {
+				advance_notice: 10,
+				interlude_length: 7_200,
+				leadin_length: 21_600,
+				region_length,
+				ideal_bulk_proportion: Perbill::from_percent(40),
+				limit_cores_offered: None,
+				renewal_bump: Perbill::from_percent(40),
+				contribution_timeout: 1_260,
+			};
+			assert_ok!(OrderCreator::set_configuration(RuntimeOrigin::root(), configuration));
+			assert_ok!(OrderCreator::schedule_next_order(RuntimeOrigin::root(), first_order));
+			assert_ok!(OrderCreator::set_requirement_profile(
+				RuntimeOrigin::root(),
+				profile_name(b"main"),
+				Some(profile(GenericRequirements::new(28_800)))
+			));
+			DispatchedOrders::take();
+
+			let mut relay_block = start;
+			let mut last_period = None;
+			let mut orders: Vec<OrderRequirements> = vec![];
+			for (block, interval) in (1..).zip(intervals) {
+				relay_block += interval;
+				RelayBlockNumber::set(relay_block);
+				OrderCreator::on_initialize(block);
+
+				let dispatched = DispatchedOrders::take();
+				let timeslice = (relay_block / timeslice_period) as u32;
+				if timeslice < first_order {
+					// No order is made before `NextOrder`.
+					prop_assert!(dispatched.is_empty());
+					continue;
+				}
+
+				// Exactly one order is made once a new bulk period starts.
+				let period = (timeslice - first_order) / region_length;
+				if last_period == Some(period) {
+					prop_assert!(dispatched.is_empty());
+					continue;
+				}
+				last_period = Some(period);
+				prop_assert_eq!(dispatched.len(), 1);
+
+				// The order is for the region of the upcoming bulk period.
+				let region_begin = first_order + (period + 1) * region_length;
+				prop_assert_eq!(
+					&dispatched[0],
+					&OrderRequirements {
+						begin: region_begin,
+						end: region_begin + region_length,
+						core_occupancy: 28_800
+					}
+				);
+				prop_assert_eq!(NextOrder::<Test>::get(), Some(region_begin));
+				orders.extend(dispatched);
+			}
+
+			// The ordered regions never overlap.
+			for pair in orders.windows(2) {
+				prop_assert!(pair[0].end <= pair[1].begin);
+			}
+			Ok(())
+		})?;
+	}
+}
+
+#[test]
+fn order_fees_are_funded() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let fee = OrderCreator::order_fee();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+
+		// Without a funding configuration nothing gets topped up.
+		OrderCreator::on_initialize(1);
+		assert!(FundedFees::get().is_empty());
+
+		<Balances as Mutate<_>>::set_balance(&BOB, fee + 1_000);
+		assert_ok!(OrderCreator::set_fee_funding(
+			RuntimeOrigin::root(),
+			Some(FeeFundingConfig { source: BOB, min_balance: 1_000 })
+		));
+
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		System::assert_has_event(Event::OrderFeeFunded { source: BOB, amount: fee }.into());
+		assert_eq!(FundedFees::get(), vec![(BOB, fee)]);
+		assert_eq!(Balances::balance(&BOB), 1_000);
+
+		// The source account is not drawn below the minimum balance, but the order is still made.
+		RelayBlockNumber::set(3_780 * timeslice_period);
+		OrderCreator::on_initialize(3);
+		System::assert_has_event(
+			Event::OrderFeeFundingFailed { error: Error::<Test>::InsufficientFeeFunds.into() }
+				.into(),
+		);
+		assert_eq!(FundedFees::get(), vec![(BOB, fee)]);
+		assert_eq!(Orders::<Test>::get(2).map(|order| order.status), Some(OrderStatus::Dispatched));
+	});
+}
+
+#[test]
+fn order_outcome_is_tracked() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let regionx = 2000;
+
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			OrderQueries::<Test>::get(0),
+			Some((
+				0,
+				RetryRecord {
+					requirements: requirements.clone(),
+					attempts: 1,
+					next_attempt: 1,
+					deadline: 1_980,
+				}
+			))
+		);
+
+		// Only the response origin can report the outcome.
+		assert_noop!(
+			OrderCreator::notify_order_status(
+				RuntimeOrigin::root(),
+				0,
+				Response::ExecutionResult(None)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			OrderCreator::notify_order_status(
+				RuntimeOrigin::signed(2001),
+				0,
+				Response::ExecutionResult(None)
+			),
+			Error::<Test>::UnexpectedResponder
+		);
+		assert_noop!(
+			OrderCreator::notify_order_status(
+				RuntimeOrigin::signed(regionx),
+				1,
+				Response::ExecutionResult(None)
+			),
+			Error::<Test>::UnknownQuery
+		);
+		assert_noop!(
+			OrderCreator::notify_order_status(RuntimeOrigin::signed(regionx), 0, Response::Null),
+			Error::<Test>::UnexpectedResponse
+		);
+
+		// RegionX rejects the order, so it gets retried.
+		assert_ok!(OrderCreator::notify_order_status(
+			RuntimeOrigin::signed(regionx),
+			0,
+			Response::ExecutionResult(Some((3, XcmError::ExpectationFalse)))
+		));
+		System::assert_has_event(
+			Event::OrderRejected { order_id: 0, error: XcmError::ExpectationFalse }.into(),
+		);
+		System::assert_last_event(
+			Event::OrderRetryScheduled { order_id: 0, attempt: 1, at: 11 }.into(),
+		);
+		assert!(OrderQueries::<Test>::get(0).is_none());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Failed));
+
+		// The retry is tracked through a new query.
+		OrderCreator::on_initialize(11);
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		assert_eq!(
+			OrderQueries::<Test>::get(1).map(|(order_id, retry)| (order_id, retry.attempts)),
+			Some((0, 2))
+		);
+
+		// This time the order is created.
+		assert_ok!(OrderCreator::notify_order_status(
+			RuntimeOrigin::signed(regionx),
+			1,
+			Response::ExecutionResult(None)
+		));
+		System::assert_last_event(Event::OrderAcknowledged { order_id: 0 }.into());
+		assert!(OrderQueries::<Test>::get(1).is_none());
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.status),
+			Some(OrderStatus::Acknowledged)
+		);
+	});
+}
+
+#[test]
+fn unanswered_orders_are_not_retried() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+
+		OrderCreator::on_initialize(1);
+		assert!(QueryExpiries::<Test>::get(102, 0).is_some());
+
+		// The response is awaited until the end of the timeout.
+		OrderCreator::on_initialize(101);
+		assert!(OrderQueries::<Test>::get(0).is_some());
+
+		// The order may have been created, so it isn't retried.
+		OrderCreator::on_initialize(102);
+		System::assert_last_event(Event::OrderResponseTimedOut { order_id: 0 }.into());
+		assert!(OrderQueries::<Test>::get(0).is_none());
+		assert!(QueryExpiries::<Test>::get(102, 0).is_none());
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Unknown));
+		assert!(Orders::<Test>::get(0).is_some_and(|order| order.is_final()));
+
+		// A late response is rejected.
+		assert_noop!(
+			OrderCreator::notify_order_status(
+				RuntimeOrigin::signed(2000),
+				0,
+				Response::ExecutionResult(None)
+			),
+			Error::<Test>::UnknownQuery
+		);
+	});
+}
+
+#[test]
+fn answered_queries_are_not_expired() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+
+		OrderCreator::on_initialize(1);
+		assert_ok!(OrderCreator::notify_order_status(
+			RuntimeOrigin::signed(2000),
+			0,
+			Response::ExecutionResult(None)
+		));
+
+		System::reset_events();
+		OrderCreator::on_initialize(102);
+		assert!(System::events().is_empty());
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.status),
+			Some(OrderStatus::Acknowledged)
+		);
+	});
+}
+
+fn genesis_configuration() -> GenesisConfigRecord<u32> {
+	GenesisConfigRecord {
+		advance_notice: 20,
+		interlude_length: 7_200,
+		leadin_length: 21_600,
+		region_length: 1_260,
+		ideal_bulk_proportion: Perbill::from_percent(100),
+		limit_cores_offered: None,
+		renewal_bump: Perbill::from_percent(2),
+		contribution_timeout: 1_260,
+	}
+}
+
+#[test]
+fn genesis_config_works() {
+	let genesis = crate::GenesisConfig::<Test> {
+		configuration: Some(genesis_configuration()),
+		next_order: Some(1_260),
+		requirement_profiles: vec![(b"main".to_vec(), profile(GenericRequirements::new(28_800)))],
+	};
+	let mut ext: sp_io::TestExternalities = genesis.build_storage().unwrap().into();
+
+	ext.execute_with(|| {
+		assert_eq!(
+			Configuration::<Test>::get(),
+			Some(genesis_configuration().into_config_record())
+		);
+		assert_eq!(NextOrder::<Test>::get(), Some(1_260));
+		assert_eq!(
+			RequirementProfiles::<Test>::get().get(&profile_name(b"main")),
+			Some(&ProfileRecord {
+				profile: profile(GenericRequirements::new(28_800)),
+				next_order: 1_260
+			})
+		);
+
+		// Orders are made without any further setup.
+		RelayBlockNumber::set(1_260 * 80);
+		OrderCreator::on_initialize(1);
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+		assert!(Orders::<Test>::get(0).is_some());
+	});
+}
+
+#[test]
+#[should_panic(
+	expected = "The next order cannot be scheduled without the Coretime chain configuration"
+)]
+fn genesis_next_order_requires_configuration() {
+	let genesis = crate::GenesisConfig::<Test> {
+		configuration: None,
+		next_order: Some(1_260),
+		requirement_profiles: vec![],
+	};
+	let _ = genesis.build_storage();
+}
+
+#[test]
+#[should_panic(expected = "Invalid requirement profile")]
+fn genesis_core_occupancy_is_checked() {
+	let genesis = crate::GenesisConfig::<Test> {
+		configuration: Some(genesis_configuration()),
+		next_order: None,
+		requirement_profiles: vec![(b"main".to_vec(), profile(GenericRequirements::new(0)))],
+	};
+	let _ = genesis.build_storage();
+}
+
+#[test]
+#[should_panic(expected = "Invalid Coretime chain configuration")]
+fn genesis_configuration_is_checked() {
+	let genesis = crate::GenesisConfig::<Test> {
+		configuration: Some(GenesisConfigRecord { region_length: 0, ..genesis_configuration() }),
+		next_order: None,
+		requirement_profiles: vec![],
+	};
+	let _ = genesis.build_storage();
+}
+
+#[test]
+fn region_duration_requirements_work() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+
+		// Only the second half of the bulk period is needed.
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			profile_name(b"main"),
+			Some(profile(GenericRequirements {
+				duration: Some(630),
+				alignment: RegionAlignment::End,
+				..GenericRequirements::new(28_800)
+			}))
+		));
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 3_150, end: 3_780, core_occupancy: 28_800 })
+		);
+		assert!(Orders::<Test>::get(1).is_none());
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+
+		// Two bulk periods are covered by three consecutive orders.
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			profile_name(b"main"),
+			Some(profile(GenericRequirements {
+				duration: Some(2_520),
+				split: 3,
+				..GenericRequirements::new(28_800)
+			}))
+		));
+		RelayBlockNumber::set(2_520 * 80);
+		OrderCreator::on_initialize(2);
+		let ordered: Vec<_> = (1..4)
+			.filter_map(Orders::<Test>::get)
+			.map(|order| (order.requirements.begin, order.requirements.end))
+			.collect();
+		assert_eq!(ordered, vec![(3_780, 4_620), (4_620, 5_460), (5_460, 6_300)]);
+		// The next orders are made once the last covered bulk period starts.
+		assert_eq!(NextOrder::<Test>::get(), Some(3_780));
+		assert_eq!(
+			RequirementProfiles::<Test>::get()
+				.get(&profile_name(b"main"))
+				.map(|record| record.next_order),
+			Some(5_040)
+		);
+
+		// The last order covers the remainder of the duration.
+		let requirements =
+			GenericRequirements { duration: Some(1_000), split: 3, ..GenericRequirements::new(1) };
+		let lengths: Vec<_> = requirements
+			.orders(0, 1_260)
+			.into_iter()
+			.map(|order| order.end - order.begin)
+			.collect();
+		assert_eq!(lengths, vec![333, 333, 334]);
+	});
+}
+
+#[test]
+fn orders_are_made_per_active_profile() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+
+		// The batch workload needs coretime every other bulk period.
+		let batch = profile_name(b"batch");
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			batch.clone(),
+			Some(RequirementProfile { cadence: 2, ..profile(GenericRequirements::new(14_400)) })
+		));
+
+		OrderCreator::on_initialize(1);
+		let batch_requirements =
+			OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 14_400 };
+		System::assert_has_event(
+			Event::OrderCreated {
+				order_id: 0,
+				profile: batch.clone(),
+				requirements: batch_requirements.clone(),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::OrderCreated {
+				order_id: 1,
+				profile: profile_name(b"main"),
+				requirements: OrderRequirements {
+					begin: 2_520,
+					end: 3_780,
+					core_occupancy: 28_800,
+				},
+			}
+			.into(),
+		);
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.profile), Some(batch.clone()));
+
+		// Only the main profile is due in the following bulk period.
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		assert_eq!(NextOrderId::<Test>::get(), 3);
+		assert_eq!(Orders::<Test>::get(2).map(|order| order.profile), Some(profile_name(b"main")));
+
+		// Inactive profiles don't make orders.
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			profile_name(b"main"),
+			Some(RequirementProfile { active: false, ..profile(GenericRequirements::new(28_800)) })
+		));
+		RelayBlockNumber::set(3_780 * timeslice_period);
+		OrderCreator::on_initialize(3);
+		assert_eq!(NextOrderId::<Test>::get(), 4);
+		assert_eq!(
+			Orders::<Test>::get(3).map(|order| (order.profile, order.requirements)),
+			Some((batch, OrderRequirements { begin: 5_040, end: 6_300, core_occupancy: 14_400 }))
+		);
+	});
+}
+
+#[test]
+fn scheduled_requirement_changes_work() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let main = profile_name(b"main");
+		let requirements = GenericRequirements::new(43_200); // 75%
+
+		// Failure: Bad origin
+		assert_noop!(
+			OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::signed(ALICE),
+				main.clone(),
+				3_780,
+				requirements.clone()
+			),
+			BadOrigin
+		);
+
+		// Failure: Unknown profile
+		assert_noop!(
+			OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				profile_name(b"batch"),
+				3_780,
+				requirements.clone()
+			),
+			Error::<Test>::UnknownProfile
+		);
+
+		// Failure: The timeslice has already started
+		assert_noop!(
+			OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				main.clone(),
+				1_260,
+				requirements.clone()
+			),
+			Error::<Test>::InvalidChangeTimeslice
+		);
+
+		// Failure: Invalid requirements
+		assert_noop!(
+			OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				main.clone(),
+				3_780,
+				GenericRequirements::new(0)
+			),
+			Error::<Test>::InvalidCoreOccupancy
+		);
+
+		// Changes are kept sorted by the timeslice from which they apply.
+		for at in [5_040, 3_780] {
+			assert_ok!(OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				main.clone(),
+				at,
+				requirements.clone()
+			));
+		}
+		System::assert_last_event(
+			Event::RequirementsChangeScheduled {
+				profile: main.clone(),
+				at: 3_780,
+				requirements: requirements.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			ScheduledChanges::<Test>::get()
+				.iter()
+				.map(|change| change.at)
+				.collect::<Vec<_>>(),
+			vec![3_780, 5_040]
+		);
+
+		// Failure: Too many scheduled changes
+		assert_noop!(
+			OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				main.clone(),
+				6_300,
+				requirements.clone()
+			),
+			Error::<Test>::TooManyScheduledChanges
+		);
+
+		// Failure: Unknown change
+		assert_noop!(
+			OrderCreator::cancel_requirements_change(RuntimeOrigin::root(), main.clone(), 6_300),
+			Error::<Test>::UnknownChange
+		);
+
+		assert_ok!(OrderCreator::cancel_requirements_change(
+			RuntimeOrigin::root(),
+			main.clone(),
+			5_040
+		));
+		System::assert_last_event(
+			Event::RequirementsChangeCancelled { profile: main.clone(), at: 5_040 }.into(),
+		);
+		assert_eq!(ScheduledChanges::<Test>::get().len(), 1);
+
+		// The region of the upcoming bulk period begins before the change.
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.requirements.core_occupancy),
+			Some(28_800)
+		);
+
+		// The change is applied before the next order is made.
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		System::assert_has_event(
+			Event::RequirementsChangeApplied {
+				profile: main.clone(),
+				requirements: requirements.clone(),
+			}
+			.into(),
+		);
+		assert!(ScheduledChanges::<Test>::get().is_empty());
+		assert_eq!(
+			RequirementProfiles::<Test>::get()
+				.get(&main)
+				.map(|record| record.profile.requirements.clone()),
+			Some(requirements)
+		);
+		assert_eq!(
+			Orders::<Test>::get(1).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 3_780, end: 5_040, core_occupancy: 43_200 })
+		);
+
+		// Removing a profile removes its scheduled changes.
+		let batch = profile_name(b"batch");
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			batch.clone(),
+			Some(profile(GenericRequirements::new(14_400)))
+		));
+		for name in [&main, &batch] {
+			assert_ok!(OrderCreator::schedule_requirements_change(
+				RuntimeOrigin::root(),
+				name.clone(),
+				6_300,
+				GenericRequirements::new(57_600)
+			));
+		}
+		assert_ok!(OrderCreator::set_requirement_profile(RuntimeOrigin::root(), batch, None));
+		assert_eq!(
+			ScheduledChanges::<Test>::get()
+				.iter()
+				.map(|change| change.profile.clone())
+				.collect::<Vec<_>>(),
+			vec![main]
+		);
+	});
+}
+
+#[test]
+fn core_occupancy_follows_usage() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let main = profile_name(b"main");
+
+		// Failure: Invalid occupancy bounds
+		for (min, max) in [(0, 28_800), (28_800, 14_400), (14_400, CORE_PARTS + 1)] {
+			assert_noop!(
+				OrderCreator::set_requirement_profile(
+					RuntimeOrigin::root(),
+					main.clone(),
+					Some(profile(GenericRequirements {
+						occupancy_bounds: Some(OccupancyBounds { min, max }),
+						..GenericRequirements::new(28_800)
+					}))
+				),
+				Error::<Test>::InvalidOccupancyBounds
+			);
+		}
+
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			main,
+			Some(profile(GenericRequirements {
+				occupancy_bounds: Some(OccupancyBounds { min: 14_400, max: 43_200 }),
+				..GenericRequirements::new(28_800)
+			}))
+		));
+
+		// Without usage statistics the fixed core occupancy is used.
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.requirements.core_occupancy),
+			Some(28_800)
+		);
+
+		// The occupancy matches the usage of the parachain.
+		CoreUsage::set(Some(Perbill::from_percent(60)));
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		assert_eq!(
+			Orders::<Test>::get(1).map(|order| order.requirements.core_occupancy),
+			Some(34_560)
+		);
+
+		// But is kept within the bounds.
+		CoreUsage::set(Some(Perbill::from_percent(10)));
+		RelayBlockNumber::set(3_780 * timeslice_period);
+		OrderCreator::on_initialize(3);
+		assert_eq!(
+			Orders::<Test>::get(2).map(|order| order.requirements.core_occupancy),
+			Some(14_400)
+		);
+
+		CoreUsage::set(Some(Perbill::from_percent(100)));
+		RelayBlockNumber::set(5_040 * timeslice_period);
+		OrderCreator::on_initialize(4);
+		assert_eq!(
+			Orders::<Test>::get(3).map(|order| order.requirements.core_occupancy),
+			Some(43_200)
+		);
+	});
+}
+
+#[test]
+fn next_orders_preview_works() {
+	new_test_ext().execute_with(|| {
+		// Nothing to preview without the configuration.
+		assert!(OrderCreator::next_orders().is_empty());
+
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let main = profile_name(b"main");
+
+		assert_eq!(OrderCreator::current_timeslice(), 1_260);
+		let preview = OrderCreator::next_orders();
+		assert_eq!(
+			preview,
+			vec![(
+				main.clone(),
+				OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 }
+			)]
+		);
+
+		// The preview matches the order that actually gets created.
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| (order.profile, order.requirements)),
+			preview.into_iter().next()
+		);
+
+		// Scheduled changes and missed bulk periods are taken into account without touching the
+		// state.
+		assert_ok!(OrderCreator::schedule_requirements_change(
+			RuntimeOrigin::root(),
+			main.clone(),
+			6_300,
+			GenericRequirements::new(43_200)
+		));
+		RelayBlockNumber::set(5_040 * timeslice_period);
+		System::reset_events();
+		assert_eq!(
+			OrderCreator::next_orders(),
+			vec![(
+				main.clone(),
+				OrderRequirements { begin: 6_300, end: 7_560, core_occupancy: 43_200 }
+			)]
+		);
+		assert!(System::events().is_empty());
+		assert_eq!(ScheduledChanges::<Test>::get().len(), 1);
+		assert_eq!(NextOrder::<Test>::get(), Some(2_520));
+
+		// No orders are made until the order creation is resumed.
+		assert_ok!(OrderCreator::pause(RuntimeOrigin::root(), None));
+		assert!(OrderCreator::next_orders().is_empty());
+
+		// The orders are made once the order creation resumes by itself.
+		assert_ok!(OrderCreator::pause(RuntimeOrigin::root(), Some(6_400)));
+		assert_eq!(
+			OrderCreator::next_orders(),
+			vec![(
+				main.clone(),
+				OrderRequirements { begin: 7_560, end: 8_820, core_occupancy: 43_200 }
+			)]
+		);
+		RelayBlockNumber::set(6_400 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		assert_eq!(
+			Orders::<Test>::get(1).map(|order| (order.profile, order.requirements)),
+			Some((main, OrderRequirements { begin: 7_560, end: 8_820, core_occupancy: 43_200 }))
+		);
+	});
+}
+
+#[test]
+fn preview_order_messages_works() {
+	new_test_ext().execute_with(|| {
+		// Failure: Nothing to preview
+		assert_noop!(
+			OrderCreator::preview_order_messages(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NoUpcomingOrders
+		);
+
+		setup_order_creation();
+		let requirements = OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+		let message = VersionedXcm::from(Xcm::<()>(vec![Instruction::Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most: DEFAULT_ORDER_CALL_WEIGHT,
+			call: <CallEncoder as crate::CallEncoder>::order_creation_call(requirements).into(),
+		}]));
+
+		// Failure: Bad origin
+		assert_noop!(OrderCreator::preview_order_messages(RuntimeOrigin::none()), BadOrigin);
+
+		assert_eq!(
+			OrderCreator::next_order_messages(),
+			Ok(vec![(profile_name(b"main"), RegionXLocation::get(), message.clone())])
+		);
+
+		assert_ok!(OrderCreator::preview_order_messages(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(
+			Event::<Test>::OrderMessagePreviewed {
+				profile: profile_name(b"main"),
+				destination: Box::new(RegionXLocation::get()),
+				message,
+			}
+			.into(),
+		);
+		// Nothing was sent.
+		assert_eq!(NextQueryId::get(), 0);
+		assert_eq!(NextOrderId::<Test>::get(), 0);
+
+		// Failure: The message cannot be built
+		DispatchFailure::set(Some(SendError::Unroutable));
+		assert_noop!(
+			OrderCreator::preview_order_messages(RuntimeOrigin::root()),
+			Error::<Test>::Unreachable
+		);
+	});
+}
+
+#[test]
+fn pause_and_resume_work() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+
+		// Failure: Bad origin
+		assert_noop!(OrderCreator::pause(RuntimeOrigin::signed(ALICE), None), BadOrigin);
+		assert_noop!(OrderCreator::resume(RuntimeOrigin::signed(ALICE)), BadOrigin);
+
+		// Failure: Not paused
+		assert_noop!(OrderCreator::resume(RuntimeOrigin::root()), Error::<Test>::NotPaused);
+
+		// Failure: The timeslice has already started
+		assert_noop!(
+			OrderCreator::pause(RuntimeOrigin::root(), Some(1_260)),
+			Error::<Test>::InvalidPauseTimeslice
+		);
+
+		assert_ok!(OrderCreator::pause(RuntimeOrigin::root(), None));
+		System::assert_last_event(Event::<Test>::OrderCreationPaused { until: None }.into());
+		assert_eq!(Paused::<Test>::get(), Some(PauseRecord { until: None }));
+
+		// No orders are made while paused, and the requirements are kept.
+		OrderCreator::on_initialize(1);
+		RelayBlockNumber::set(3_800 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		assert_eq!(NextOrderId::<Test>::get(), 0);
+		assert_eq!(NextOrder::<Test>::get(), Some(1_260));
+		assert!(RequirementProfiles::<Test>::get().contains_key(&profile_name(b"main")));
+
+		// Resuming skips the bulk periods which started in the meantime.
+		assert_ok!(OrderCreator::resume(RuntimeOrigin::root()));
+		System::assert_last_event(
+			Event::<Test>::OrderCreationResumed { next_order: Some(3_780) }.into(),
+		);
+		assert!(Paused::<Test>::get().is_none());
+
+		System::reset_events();
+		OrderCreator::on_initialize(3);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::OrderCreator(Event::MissedOrdersSkipped { .. })
+		)));
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 5_040, end: 6_300, core_occupancy: 28_800 })
+		);
+		assert_eq!(NextOrder::<Test>::get(), Some(5_040));
+
+		// The order creation resumes by itself once the specified timeslice is reached.
+		assert_ok!(OrderCreator::pause(RuntimeOrigin::root(), Some(6_300)));
+		RelayBlockNumber::set(5_040 * timeslice_period);
+		OrderCreator::on_initialize(4);
+		assert_eq!(NextOrderId::<Test>::get(), 1);
+
+		RelayBlockNumber::set(6_300 * timeslice_period);
+		OrderCreator::on_initialize(5);
+		assert!(Paused::<Test>::get().is_none());
+		assert_eq!(
+			Orders::<Test>::get(1).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 7_560, end: 8_820, core_occupancy: 28_800 })
+		);
+	});
+}
+
+#[test]
+fn force_order_works() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let main = profile_name(b"main");
+		let requirements = OrderRequirements { begin: 1_300, end: 2_520, core_occupancy: 57_600 };
+
+		// Failure: Bad origin
+		assert_noop!(
+			OrderCreator::force_order(RuntimeOrigin::signed(ALICE), main.clone(), None),
+			BadOrigin
+		);
+
+		// Failure: Unknown profile
+		assert_noop!(
+			OrderCreator::force_order(RuntimeOrigin::root(), profile_name(b"batch"), None),
+			Error::<Test>::UnknownProfile
+		);
+
+		// Failure: Invalid requirements
+		assert_noop!(
+			OrderCreator::force_order(
+				RuntimeOrigin::root(),
+				main.clone(),
+				Some(OrderRequirements { end: 1_300, ..requirements.clone() })
+			),
+			Error::<Test>::InvalidOrderRegion
+		);
+		assert_noop!(
+			OrderCreator::force_order(
+				RuntimeOrigin::root(),
+				main.clone(),
+				Some(OrderRequirements { core_occupancy: 0, ..requirements.clone() })
+			),
+			Error::<Test>::InvalidCoreOccupancy
+		);
+
+		// Failure: The region has already started
+		assert_noop!(
+			OrderCreator::force_order(
+				RuntimeOrigin::root(),
+				main.clone(),
+				Some(OrderRequirements { begin: 1_260, ..requirements.clone() })
+			),
+			Error::<Test>::RegionAlreadyStarted
+		);
+
+		// An order which cannot be sent is kept in the order history and abandoned.
+		DispatchFailure::set(Some(SendError::Transport("")));
+		assert_ok!(OrderCreator::force_order(RuntimeOrigin::root(), main.clone(), None));
+		System::assert_has_event(Event::<Test>::OrderForced { order_id: 0 }.into());
+		System::assert_has_event(
+			Event::<Test>::OrderDispatchFailed { order_id: 0, error: SendError::Transport("") }
+				.into(),
+		);
+		System::assert_last_event(Event::<Test>::OrderAbandoned { order_id: 0 }.into());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Expired));
+		assert!(PendingRetries::<Test>::get(0).is_none());
+		DispatchFailure::set(None);
+
+		assert_ok!(OrderCreator::force_order(
+			RuntimeOrigin::root(),
+			main.clone(),
+			Some(requirements.clone())
+		));
+		System::assert_last_event(Event::<Test>::OrderForced { order_id: 1 }.into());
+		assert_eq!(
+			Orders::<Test>::get(1).map(|order| (order.profile, order.requirements, order.status)),
+			Some((main.clone(), requirements, OrderStatus::Dispatched))
+		);
+
+		// The order is made for the bulk period following the one we are currently in, whose
+		// region has already started.
+		RelayBlockNumber::set(2_600 * timeslice_period);
+		assert_ok!(OrderCreator::force_order(RuntimeOrigin::root(), main.clone(), None));
+		assert_eq!(
+			Orders::<Test>::get(2).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 3_780, end: 5_040, core_occupancy: 28_800 })
+		);
+
+		// The order schedule is not affected.
+		assert_eq!(NextOrder::<Test>::get(), Some(1_260));
+		assert_eq!(
+			RequirementProfiles::<Test>::get().get(&main).map(|record| record.next_order),
+			Some(1_260)
+		);
+
+		// Forced orders are not retried.
+		assert_ok!(OrderCreator::notify_order_status(
+			RuntimeOrigin::signed(2000),
+			1,
+			Response::ExecutionResult(Some((0, XcmError::Barrier)))
+		));
+		assert!(PendingRetries::<Test>::get(2).is_none());
+		assert_eq!(Orders::<Test>::get(2).map(|order| order.status), Some(OrderStatus::Expired));
+	});
+}
+
+#[test]
+fn sync_coretime_state_works() {
+	new_test_ext().execute_with(|| {
+		let configuration = CoretimeConfigRecord {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		};
+		let (relay_root, relay_proof, coretime_proof) =
+			coretime_state_proofs(Some(configuration.clone()), Some(coretime_sale_info(2_530)));
+
+		// Failure: Bad origin
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::root(),
+				relay_proof.clone(),
+				coretime_proof.clone()
+			),
+			BadOrigin
+		);
+
+		// Failure: The proofs are too large
+		let padded_proof = StorageProof::merge([
+			coretime_proof.clone(),
+			StorageProof::new([[0u8; 4_096].to_vec()]),
+		]);
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				relay_proof.clone(),
+				padded_proof
+			),
+			Error::<Test>::StateProofTooLarge
+		);
+
+		// Failure: No relay chain state root
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				relay_proof.clone(),
+				coretime_proof.clone()
+			),
+			Error::<Test>::RelayStateRootUnavailable
+		);
+
+		// Failure: The proofs don't match the relay chain state root
+		RelayStateRoot::set(Some(H256::repeat_byte(1)));
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				relay_proof.clone(),
+				coretime_proof.clone()
+			),
+			Error::<Test>::InvalidStateProof
+		);
+		RelayStateRoot::set(Some(relay_root));
+		let (_, _, other_coretime_proof) =
+			coretime_state_proofs(Some(configuration.clone()), Some(coretime_sale_info(3_780)));
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				relay_proof.clone(),
+				other_coretime_proof
+			),
+			Error::<Test>::InvalidStateProof
+		);
+
+		// Failure: There is no ongoing sale
+		let (missing_root, missing_relay_proof, missing_coretime_proof) =
+			coretime_state_proofs(Some(configuration.clone()), None);
+		RelayStateRoot::set(Some(missing_root));
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				missing_relay_proof,
+				missing_coretime_proof
+			),
+			Error::<Test>::MissingCoretimeState
+		);
+
+		// Failure: Invalid configuration
+		let (invalid_root, invalid_relay_proof, invalid_coretime_proof) = coretime_state_proofs(
+			Some(CoretimeConfigRecord { region_length: 0, ..configuration.clone() }),
+			Some(coretime_sale_info(2_530)),
+		);
+		RelayStateRoot::set(Some(invalid_root));
+		assert_noop!(
+			OrderCreator::sync_coretime_state(
+				RuntimeOrigin::signed(ALICE),
+				invalid_relay_proof,
+				invalid_coretime_proof
+			),
+			Error::<Test>::InvalidRegionLength
+		);
+
+		// The regions of the ongoing sale are ordered at the start of the preceding bulk period.
+		RelayStateRoot::set(Some(relay_root));
+		NextOrder::<Test>::put(1_260);
+		let result = OrderCreator::sync_coretime_state(
+			RuntimeOrigin::signed(ALICE),
+			relay_proof.clone(),
+			coretime_proof.clone(),
+		);
+		// Updating the state is free.
+		assert_eq!(result.map(|info| info.pays_fee), Ok(Pays::No));
+		let expected_configuration = ConfigRecordOf::<Test> {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		};
+		System::assert_last_event(
+			Event::<Test>::CoretimeStateSynced {
+				configuration: expected_configuration.clone(),
+				next_order: 1_270,
+			}
+			.into(),
+		);
+		assert_eq!(Configuration::<Test>::get(), Some(expected_configuration));
+		assert_eq!(NextOrder::<Test>::get(), Some(1_270));
+
+		// An order schedule which is already aligned is kept. Proving the state which is already
+		// known is paid for.
+		NextOrder::<Test>::put(2_530);
+		let result = OrderCreator::sync_coretime_state(
+			RuntimeOrigin::signed(ALICE),
+			relay_proof,
+			coretime_proof,
+		);
+		assert_eq!(result.map(|info| info.pays_fee), Ok(Pays::Yes));
+		assert_eq!(NextOrder::<Test>::get(), Some(2_530));
+	});
+}
+
+#[test]
+fn on_initialize_weight_is_accounted() {
+	new_test_ext().execute_with(|| {
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		// The query expiries, the pause and the earliest due pending retry and queued order are
+		// always read.
+		let idle = db_weight.reads(4) + MockWeightInfo::on_initialize_idle();
+
+		// Nothing is configured.
+		assert_eq!(OrderCreator::on_initialize(1), idle);
+
+		// The next order is not due yet.
+		setup_order_creation();
+		RelayBlockNumber::set(1_000 * timeslice_period);
+		assert_eq!(OrderCreator::on_initialize(1), idle);
+
+		// An order is made, but fails to be sent.
+		RelayBlockNumber::set(1_260 * timeslice_period);
+		DispatchFailure::set(Some(SendError::Transport("")));
+		assert_eq!(
+			OrderCreator::on_initialize(1),
+			db_weight.reads_writes(5, 1) +
+				MockWeightInfo::on_initialize_order(1) +
+				MockWeightInfo::send_queued_order()
+		);
+		assert!(PendingRetries::<Test>::get(0).is_some());
+
+		// The order is retried. It is queued and then sent like a new order.
+		DispatchFailure::set(None);
+		assert_eq!(
+			OrderCreator::on_initialize(11),
+			idle + db_weight.reads_writes(2, 4) +
+				db_weight.reads_writes(1, 1) +
+				MockWeightInfo::send_queued_order()
+		);
+		assert!(PendingRetries::<Test>::get(0).is_none());
+
+		// Multiple orders are made at once.
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			profile_name(b"main"),
+			Some(profile(GenericRequirements { split: 2, ..GenericRequirements::new(28_800) }))
+		));
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		assert_eq!(
+			OrderCreator::on_initialize(12),
+			db_weight.reads_writes(6, 1) +
+				MockWeightInfo::on_initialize_order(2) +
+				MockWeightInfo::send_queued_order() * 2
+		);
+
+		// Nothing is done while paused.
+		assert_ok!(OrderCreator::pause(RuntimeOrigin::root(), Some(3_780)));
+		assert_eq!(OrderCreator::on_initialize(13), db_weight.reads(2));
+
+		// The order creation resumes by itself.
+		RelayBlockNumber::set(3_780 * timeslice_period);
+		assert_eq!(
+			OrderCreator::on_initialize(14),
+			db_weight.reads_writes(6, 1) +
+				MockWeightInfo::resume() +
+				MockWeightInfo::on_initialize_order(2) +
+				MockWeightInfo::send_queued_order() * 2
+		);
+	});
+}
+
+#[test]
+fn due_orders_are_sent_when_idle() {
+	new_test_ext().execute_with(|| {
+		setup_order_creation();
+		MaxDispatchDelay::set(5);
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let order_weight = MockWeightInfo::send_queued_order() + db_weight.reads(1);
+
+		// The order is queued instead of being sent right away.
+		OrderCreator::on_initialize(1);
+		assert!(QueuedOrders::<Test>::get(0).is_some());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Queued));
+
+		// Not enough weight left in the block.
+		assert_eq!(OrderCreator::on_idle(1, order_weight), db_weight.reads(1));
+		assert!(QueuedOrders::<Test>::get(0).is_some());
+
+		// Nothing is sent while paused.
+		Paused::<Test>::put(PauseRecord { until: None });
+		assert_eq!(OrderCreator::on_idle(1, Weight::MAX), db_weight.reads(1));
+		Paused::<Test>::kill();
+
+		assert_eq!(
+			OrderCreator::on_idle(1, order_weight + db_weight.reads(1)),
+			order_weight + db_weight.reads(1)
+		);
+		assert!(QueuedOrders::<Test>::get(0).is_none());
+		assert_eq!(Orders::<Test>::get(0).map(|order| order.status), Some(OrderStatus::Dispatched));
+
+		// Orders which don't get sent in time are sent in `on_initialize`.
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		RelayBlockNumber::set(2_520 * timeslice_period);
+		OrderCreator::on_initialize(2);
+		// The order sent in `on_idle` is still considered the earliest due.
+		assert_eq!(QueuedOrdersDue::<Test>::get(), Some(6));
+		OrderCreator::on_initialize(6);
+		assert!(QueuedOrders::<Test>::get(1).is_some());
+		assert_eq!(QueuedOrdersDue::<Test>::get(), Some(7));
+		OrderCreator::on_initialize(7);
+		assert!(QueuedOrders::<Test>::get(1).is_none());
+		assert_eq!(QueuedOrdersDue::<Test>::get(), None);
+		assert_eq!(Orders::<Test>::get(1).map(|order| order.status), Some(OrderStatus::Dispatched));
+
+		// Orders whose region started while queued are abandoned.
+		RelayBlockNumber::set(3_780 * timeslice_period);
+		OrderCreator::on_initialize(8);
+		RelayBlockNumber::set(5_040 * timeslice_period);
+		OrderCreator::on_idle(9, Weight::MAX);
+		System::assert_last_event(Event::OrderAbandoned { order_id: 2 }.into());
+		assert_eq!(Orders::<Test>::get(2).map(|order| order.status), Some(OrderStatus::Expired));
+	});
+}
+
+#[test]
+fn coretime_requirements_are_migrated_to_a_profile() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<OrderCreator>();
+		NextOrder::<Test>::put(1_260);
+		migration::v0::CoretimeRequirements::<Test>::put(migration::v0::GenericRequirements {
+			core_occupancy: 28_800,
+		});
+
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(OrderCreator::on_chain_storage_version(), 1);
+		assert!(!migration::v0::CoretimeRequirements::<Test>::exists());
+		assert_eq!(
+			RequirementProfiles::<Test>::get().get(&profile_name(b"main")),
+			Some(&ProfileRecord {
+				profile: profile(GenericRequirements::new(28_800)),
+				next_order: 1_260
+			})
+		);
+
+		// Orders keep being made for the migrated requirements.
+		let configuration = ConfigRecordOf::<Test> {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		};
+		assert_ok!(OrderCreator::set_configuration(RuntimeOrigin::root(), configuration));
+		let timeslice_period: u64 = <Test as Config>::TimeslicePeriod::get();
+		RelayBlockNumber::set(1_260 * timeslice_period);
+		OrderCreator::on_initialize(1);
+		assert_eq!(
+			Orders::<Test>::get(0).map(|order| order.requirements),
+			Some(OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 })
+		);
+
+		// The migration only runs once.
+		migration::v0::CoretimeRequirements::<Test>::put(migration::v0::GenericRequirements {
+			core_occupancy: 57_600,
+		});
+		migration::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(migration::v0::CoretimeRequirements::<Test>::exists());
+	});
+}
+
+#[test]
+fn configuration_is_migrated_to_coretime_block_numbers() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<OrderCreator>();
+		migration::v2::Configuration::<Test>::put(pallet_broker::ConfigRecord {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		});
+
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(OrderCreator::on_chain_storage_version(), 2);
+		assert_eq!(
+			Configuration::<Test>::get(),
+			Some(ConfigRecordOf::<Test> {
+				advance_notice: 10,
+				interlude_length: 7_200,
+				leadin_length: 21_600,
+				region_length: 1_260,
+				ideal_bulk_proportion: Perbill::from_percent(40),
+				limit_cores_offered: None,
+				renewal_bump: Perbill::from_percent(40),
+				contribution_timeout: 1_260,
+			})
+		);
+
+		// The migration only runs once.
+		let configuration = Configuration::<Test>::get();
+		migration::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Configuration::<Test>::get(), configuration);
+	});
+}
@@ -1,18 +1,78 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 pub use cumulus_primitives_core::ParaId;
 use frame_system::pallet_prelude::BlockNumberFor;
-use pallet_broker::{ConfigRecord, PartsOf57600, Timeslice};
+use pallet_broker::{ConfigRecord, CoreIndex, PartsOf57600, Timeslice};
 use scale_info::{prelude::vec::Vec, TypeInfo};
-use sp_runtime::traits::BlockNumberProvider;
+use serde::{Deserialize, Serialize};
+use sp_core::ConstU32;
+use sp_runtime::{traits::BlockNumberProvider, BoundedVec, Perbill, SaturatedConversion};
+use xcm::latest::QueryId;
 
 /// Order identifier.
 pub type OrderId = u32;
 
+/// The name of a requirement profile.
+pub type ProfileName = BoundedVec<u8, ConstU32<32>>;
+
+/// The number of parts an entire core is divided into.
+pub const CORE_PARTS: PartsOf57600 = 57_600;
+
 /// Relay chain block number.
 pub type RCBlockNumberOf<T> =
 	<<T as crate::Config>::RCBlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
-pub type ConfigRecordOf<T> = ConfigRecord<BlockNumberFor<T>, RCBlockNumberOf<T>>;
+/// Coretime chain block number.
+pub type CoretimeBlockNumberOf<T> =
+	<<T as crate::Config>::CoretimeChain as crate::CoretimeChain>::BlockNumber;
+
+/// The configuration of the Coretime chain.
+///
+/// The lengths of the sale periods are expressed in Coretime chain blocks.
+pub type ConfigRecordOf<T> = ConfigRecord<CoretimeBlockNumberOf<T>, RCBlockNumberOf<T>>;
+
+/// The configuration of the Coretime chain as specified at genesis.
+///
+/// Mirrors `ConfigRecord`, which can't be used in the genesis config since it isn't
+/// serializable. The relay chain block number is fixed to `u32` for the same reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisConfigRecord<BlockNumber> {
+	/// The number of Relay-chain blocks in advance which scheduling should be fixed.
+	pub advance_notice: u32,
+	/// The length in blocks of the Interlude Period for forthcoming sales.
+	pub interlude_length: BlockNumber,
+	/// The length in blocks of the Leadin Period for forthcoming sales.
+	pub leadin_length: BlockNumber,
+	/// The length in timeslices of Regions which are up for sale in forthcoming sales.
+	pub region_length: Timeslice,
+	/// The proportion of cores available for sale which should be sold in order for the price
+	/// to remain the same in the next sale.
+	pub ideal_bulk_proportion: Perbill,
+	/// An artificial limit to the number of cores which are allowed to be sold.
+	pub limit_cores_offered: Option<CoreIndex>,
+	/// The amount by which the renewal price increases each sale period.
+	pub renewal_bump: Perbill,
+	/// The duration by which rewards for contributions to the InstaPool must be collected.
+	pub contribution_timeout: Timeslice,
+}
+
+impl<BlockNumber> GenesisConfigRecord<BlockNumber> {
+	/// Converts the genesis configuration into the `ConfigRecord` kept in storage.
+	pub fn into_config_record<RelayBlockNumber: From<u32>>(
+		self,
+	) -> ConfigRecord<BlockNumber, RelayBlockNumber> {
+		ConfigRecord {
+			advance_notice: self.advance_notice.into(),
+			interlude_length: self.interlude_length,
+			leadin_length: self.leadin_length,
+			region_length: self.region_length,
+			ideal_bulk_proportion: self.ideal_bulk_proportion,
+			limit_cores_offered: self.limit_cores_offered,
+			renewal_bump: self.renewal_bump,
+			contribution_timeout: self.contribution_timeout,
+		}
+	}
+}
 
 /// Specifies the requirements of a Coretime order.
 #[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -25,15 +85,301 @@ pub struct OrderRequirements {
 	pub core_occupancy: PartsOf57600,
 }
 
+/// The status of an order.
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum OrderStatus {
+	/// The order was sent to the RegionX parachain.
+	Dispatched,
+	/// The order couldn't be sent to the RegionX parachain.
+	Failed,
+	/// The RegionX parachain confirmed the creation of the order.
+	Acknowledged,
+	/// The order is no longer useful and won't be attempted again.
+	Expired,
+	/// The order is waiting to be sent to the RegionX parachain.
+	Queued,
+	/// The RegionX parachain didn't report the outcome of the order in time.
+	///
+	/// The order isn't attempted again, since it may have been created.
+	Unknown,
+}
+
+/// Information about an order made by the parachain.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OrderRecord<BlockNumber, Balance> {
+	/// The requirement profile for which the order was made.
+	pub profile: ProfileName,
+	/// The requirements of the order.
+	pub requirements: OrderRequirements,
+	/// The block at which the order was last attempted to be sent.
+	pub dispatched_at: BlockNumber,
+	/// The fee paid for the execution of the order on the RegionX parachain.
+	pub fee: Balance,
+	/// The current status of the order.
+	pub status: OrderStatus,
+	/// The id of the query registered for the outcome of the order, if any.
+	pub query_id: Option<QueryId>,
+}
+
+impl<BlockNumber, Balance> OrderRecord<BlockNumber, Balance> {
+	/// Whether the status of the order won't change anymore.
+	pub fn is_final(&self) -> bool {
+		match self.status {
+			OrderStatus::Acknowledged | OrderStatus::Expired | OrderStatus::Unknown => true,
+			// Without a query the RegionX parachain won't report the outcome of the order.
+			OrderStatus::Dispatched => self.query_id.is_none(),
+			OrderStatus::Failed | OrderStatus::Queued => false,
+		}
+	}
+}
+
+pub type OrderRecordOf<T> = OrderRecord<BlockNumberFor<T>, <T as crate::Config>::RelaychainBalance>;
+
+/// An order which failed to be dispatched and is scheduled to be attempted again.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RetryRecord<BlockNumber> {
+	/// The requirements of the order.
+	pub requirements: OrderRequirements,
+	/// The number of failed dispatch attempts so far.
+	pub attempts: u32,
+	/// The block at which the next attempt will be made.
+	pub next_attempt: BlockNumber,
+	/// The timeslice from which the order is no longer useful and will be abandoned.
+	pub deadline: Timeslice,
+}
+
+impl<BlockNumber> RetryRecord<BlockNumber> {
+	/// Whether the order is no longer useful at `current_timeslice`.
+	///
+	/// This is the case once the deadline is reached or the ordered region has started.
+	pub fn is_expired(&self, current_timeslice: Timeslice) -> bool {
+		current_timeslice >= self.deadline || current_timeslice >= self.requirements.begin
+	}
+}
+
 /// Generic Coretime region requirements for the parachain.
 ///
 /// Based on this we will construct `OrderRequirements` per order.
-///
-/// Currently, we only support requirements based on core occupancy, not on region duration.
-#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
 pub struct GenericRequirements {
 	/// The minimum fraction of the core that the region should occupy.
+	///
+	/// Used as is, unless the occupancy is derived from the usage of the parachain.
 	pub core_occupancy: PartsOf57600,
+	/// If set, the core occupancy is derived from the recent usage of the parachain and
+	/// clamped between the bounds.
+	///
+	/// Falls back to `core_occupancy` when no usage statistics are available.
+	pub occupancy_bounds: Option<OccupancyBounds>,
+	/// The number of timeslices for which coretime is needed.
+	///
+	/// If `None`, coretime is needed for the entire bulk period. If longer than a bulk period,
+	/// the orders cover multiple bulk periods and are made less frequently.
+	pub duration: Option<Timeslice>,
+	/// The number of consecutive orders into which the duration is split.
+	pub split: u32,
+	/// Where the needed coretime is placed within the bulk periods covered by the orders.
+	pub alignment: RegionAlignment,
+}
+
+impl GenericRequirements {
+	/// Requirements for coretime during the entire bulk period, procured with a single order.
+	pub fn new(core_occupancy: PartsOf57600) -> Self {
+		Self {
+			core_occupancy,
+			occupancy_bounds: None,
+			duration: None,
+			split: 1,
+			alignment: RegionAlignment::Start,
+		}
+	}
+
+	/// Returns the requirements with the core occupancy derived from the `usage` of the
+	/// parachain, if configured so.
+	pub fn with_usage(&self, usage: Option<Perbill>) -> Self {
+		let mut requirements = self.clone();
+		if let (Some(bounds), Some(usage)) = (&self.occupancy_bounds, usage) {
+			let core_occupancy: PartsOf57600 =
+				usage.mul_ceil(u32::from(CORE_PARTS)).saturated_into();
+			requirements.core_occupancy = core_occupancy.clamp(bounds.min, bounds.max);
+		}
+		requirements
+	}
+
+	/// The number of bulk periods covered by the orders made at the start of a bulk period.
+	pub fn periods(&self, region_length: Timeslice) -> Timeslice {
+		let region_length = region_length.max(1);
+		self.duration.unwrap_or(region_length).div_ceil(region_length).max(1)
+	}
+
+	/// Constructs the orders needed to cover the bulk periods starting at `begin`.
+	pub fn orders(&self, begin: Timeslice, region_length: Timeslice) -> Vec<OrderRequirements> {
+		let duration = self.duration.unwrap_or(region_length).max(1);
+		let covered = self.periods(region_length).saturating_mul(region_length);
+
+		let begin = match self.alignment {
+			RegionAlignment::Start => begin,
+			RegionAlignment::End => begin.saturating_add(covered.saturating_sub(duration)),
+		};
+		let end = begin.saturating_add(duration);
+
+		// Each order must cover at least a single timeslice.
+		let split = self.split.clamp(1, duration);
+		let order_length = duration / split;
+
+		(0..split)
+			.map(|index| {
+				let order_begin = begin.saturating_add(index.saturating_mul(order_length));
+				// The last order also covers the remainder of the duration.
+				let order_end =
+					if index + 1 == split { end } else { order_begin.saturating_add(order_length) };
+				OrderRequirements {
+					begin: order_begin,
+					end: order_end,
+					core_occupancy: self.core_occupancy,
+				}
+			})
+			.collect()
+	}
+}
+
+/// The bounds within which the core occupancy derived from the usage of the parachain is kept.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct OccupancyBounds {
+	/// The minimum core occupancy.
+	pub min: PartsOf57600,
+	/// The maximum core occupancy.
+	pub max: PartsOf57600,
+}
+
+/// A named set of coretime requirements for which orders are made periodically.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RequirementProfile {
+	/// The requirements of the orders made for the profile.
+	pub requirements: GenericRequirements,
+	/// The number of bulk periods between two rounds of orders for the profile.
+	///
+	/// Orders are never made more often than the number of bulk periods they cover.
+	pub cadence: u32,
+	/// Whether orders are being made for the profile.
+	pub active: bool,
+}
+
+impl RequirementProfile {
+	/// The number of timeslices between two rounds of orders for the profile.
+	pub fn interval(&self, region_length: Timeslice) -> Timeslice {
+		self.cadence
+			.max(self.requirements.periods(region_length))
+			.saturating_mul(region_length)
+	}
+}
+
+/// A requirement profile together with its order schedule.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ProfileRecord {
+	/// The requirement profile.
+	pub profile: RequirementProfile,
+	/// The start of the bulk period at which the next orders of the profile are made.
+	pub next_order: Timeslice,
+}
+
+/// The orders made at the start of a bulk period, as planned by `Pallet::plan_orders`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderPlan {
+	/// The number of bulk periods whose orders were missed.
+	pub missed_periods: Timeslice,
+	/// The timeslice at which the ordered regions begin.
+	pub region_begin: Timeslice,
+	/// The number of scheduled requirement changes which took effect.
+	pub applied_changes: usize,
+	/// The orders to make, together with the profile each of them is made for.
+	pub orders: Vec<(ProfileName, OrderRequirements)>,
+}
+
+/// The state of a paused order creation.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct PauseRecord {
+	/// The timeslice at which the order creation resumes by itself.
+	///
+	/// If `None`, the order creation stays paused until it is explicitly resumed.
+	pub until: Option<Timeslice>,
+}
+
+/// A change to the requirements of a profile which takes effect in the future.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledChange {
+	/// The profile whose requirements are changed.
+	pub profile: ProfileName,
+	/// The timeslice from which the new requirements apply.
+	///
+	/// The change is applied to the orders for regions beginning at or after this timeslice.
+	pub at: Timeslice,
+	/// The new requirements of the profile.
+	pub requirements: GenericRequirements,
+}
+
+/// Specifies where the needed coretime is placed within the covered bulk periods.
+#[derive(
+	Encode,
+	Decode,
+	Debug,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub enum RegionAlignment {
+	/// The coretime is needed from the start of the covered bulk periods.
+	#[default]
+	Start,
+	/// The coretime is needed until the end of the covered bulk periods.
+	End,
+}
+
+/// Configuration of the order fee funding.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct FeeFundingConfig<AccountId, Balance> {
+	/// The account from which the parachain's sovereign account on RegionX is topped up.
+	pub source: AccountId,
+	/// The balance the source account keeps as a reserve.
+	///
+	/// The source account won't be drawn below this amount.
+	pub min_balance: Balance,
+}
+
+pub type FeeFundingConfigOf<T> = FeeFundingConfig<
+	<T as frame_system::Config>::AccountId,
+	<T as crate::Config>::RelaychainBalance,
+>;
+
+/// Type able to report how much coretime the parachain made use of recently.
+pub trait UsageProvider {
+	/// Returns the fraction of an entire core the parachain made use of recently.
+	///
+	/// For example, the share of relay chain blocks in which the parachain produced a block with
+	/// non-empty extrinsics, or the weight used compared to the weight available.
+	///
+	/// Returns `None` if no statistics are available.
+	fn core_usage() -> Option<Perbill>;
+}
+
+impl UsageProvider for () {
+	fn core_usage() -> Option<Perbill> {
+		None
+	}
 }
 
 pub trait CallEncoder {
@@ -0,0 +1,515 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_order_creator`.
+//!
+//! Generated from the benchmarks in `benchmarking.rs`, run natively against the mock runtime of
+//! the tests with 50 steps and 20 repeats on an Intel Xeon development machine. The storage
+//! accesses are the ones made by the pallet itself, while the execution times only approximate
+//! those of a production runtime. Runtimes should regenerate this file on their reference
+//! hardware:
+//!
+//! ./target/release/<node> benchmark pallet \
+//!     --chain=<chain> \
+//!     --pallet=pallet_order_creator \
+//!     --extrinsic='*' \
+//!     --steps=50 \
+//!     --repeat=20 \
+//!     --wasm-execution=compiled \
+//!     --output=./pallets/order-creator/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_order_creator`.
+pub trait WeightInfo {
+	fn set_configuration() -> Weight;
+	fn schedule_next_order() -> Weight;
+	fn set_requirement_profile() -> Weight;
+	fn set_fee_funding() -> Weight;
+	fn set_order_call_weight() -> Weight;
+	fn notify_order_status() -> Weight;
+	fn schedule_requirements_change() -> Weight;
+	fn cancel_requirements_change() -> Weight;
+	fn preview_order_messages(n: u32) -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
+	fn force_order(n: u32) -> Weight;
+	fn sync_coretime_state(n: u32) -> Weight;
+	fn on_initialize_idle() -> Weight;
+	fn on_initialize_order(n: u32) -> Weight;
+	fn send_queued_order() -> Weight;
+	fn dispatch() -> Weight;
+}
+
+/// Weights for `pallet_order_creator` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `OrderCreator::Configuration` (r:0 w:1)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	fn set_configuration() -> Weight {
+		Weight::from_parts(9_424_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::NextOrder` (r:0 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn schedule_next_order() -> Weight {
+		Weight::from_parts(10_078_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	fn set_requirement_profile() -> Weight {
+		Weight::from_parts(15_328_000, 1_113)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn schedule_requirements_change() -> Weight {
+		Weight::from_parts(16_842_000, 1_218)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn cancel_requirements_change() -> Weight {
+		Weight::from_parts(14_512_000, 604)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::FeeFunding` (r:0 w:1)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_fee_funding() -> Weight {
+		Weight::from_parts(9_748_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::FeeMultiplier` (r:0 w:1)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:0 w:1)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_order_call_weight() -> Weight {
+		Weight::from_parts(10_812_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::OrderQueries` (r:1 w:1)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:1 w:1)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:1)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetries` (r:0 w:1)
+	/// Proof: `OrderCreator::PendingRetries` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn notify_order_status() -> Weight {
+		Weight::from_parts(30_247_000, 5_580)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:0)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn preview_order_messages(n: u32) -> Weight {
+		Weight::from_parts(20_252_778, 3_258)
+			.saturating_add(Weight::from_parts(7_217_596, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: `OrderCreator::Paused` (r:0 w:1)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		Weight::from_parts(11_521_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:1)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		Weight::from_parts(18_999_000, 1_529)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OldestOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::OldestOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:4)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:0 w:4)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:0 w:4)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn force_order(n: u32) -> Weight {
+		Weight::from_parts(43_898_000, 4_174)
+			.saturating_add(Weight::from_parts(47_629_880, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:1)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4096]`.
+	fn sync_coretime_state(n: u32) -> Weight {
+		Weight::from_parts(18_998_519, 1_029)
+			.saturating_add(Weight::from_parts(3_176, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:0)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:1 w:0)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrdersDue` (r:1 w:0)
+	/// Proof: `OrderCreator::QueuedOrdersDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn on_initialize_idle() -> Weight {
+		Weight::from_parts(31_864_000, 6_260)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OldestOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::OldestOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:8)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:0 w:8)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:0)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:1 w:8)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrdersDue` (r:1 w:1)
+	/// Proof: `OrderCreator::QueuedOrdersDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrders` (r:9 w:8)
+	/// Proof: `OrderCreator::QueuedOrders` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn on_initialize_order(n: u32) -> Weight {
+		Weight::from_parts(31_827_625, 11_304)
+			.saturating_add(Weight::from_parts(25_020_316, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_513).saturating_mul(n.into()))
+	}
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:1)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:1 w:1)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:0 w:1)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn send_queued_order() -> Weight {
+		Weight::from_parts(68_026_000, 4_089)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn dispatch() -> Weight {
+		Weight::from_parts(2_972_000, 0)
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `OrderCreator::Configuration` (r:0 w:1)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	fn set_configuration() -> Weight {
+		Weight::from_parts(9_424_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::NextOrder` (r:0 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn schedule_next_order() -> Weight {
+		Weight::from_parts(10_078_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	fn set_requirement_profile() -> Weight {
+		Weight::from_parts(15_328_000, 1_113)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn schedule_requirements_change() -> Weight {
+		Weight::from_parts(16_842_000, 1_218)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn cancel_requirements_change() -> Weight {
+		Weight::from_parts(14_512_000, 604)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::FeeFunding` (r:0 w:1)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_fee_funding() -> Weight {
+		Weight::from_parts(9_748_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::FeeMultiplier` (r:0 w:1)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:0 w:1)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_order_call_weight() -> Weight {
+		Weight::from_parts(10_812_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::OrderQueries` (r:1 w:1)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:1 w:1)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:1)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetries` (r:0 w:1)
+	/// Proof: `OrderCreator::PendingRetries` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	fn notify_order_status() -> Weight {
+		Weight::from_parts(30_247_000, 5_580)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:0)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn preview_order_messages(n: u32) -> Weight {
+		Weight::from_parts(20_252_778, 3_258)
+			.saturating_add(Weight::from_parts(7_217_596, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: `OrderCreator::Paused` (r:0 w:1)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		Weight::from_parts(11_521_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:1)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		Weight::from_parts(18_999_000, 1_529)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:0)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OldestOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::OldestOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:4)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:0 w:4)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:0 w:4)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:0)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 4]`.
+	fn force_order(n: u32) -> Weight {
+		Weight::from_parts(43_898_000, 4_174)
+			.saturating_add(Weight::from_parts(47_629_880, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:1)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 4096]`.
+	fn sync_coretime_state(n: u32) -> Weight {
+		Weight::from_parts(18_998_519, 1_029)
+			.saturating_add(Weight::from_parts(3_176, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:0)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:1 w:0)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrdersDue` (r:1 w:0)
+	/// Proof: `OrderCreator::QueuedOrdersDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	fn on_initialize_idle() -> Weight {
+		Weight::from_parts(31_864_000, 6_260)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `OrderCreator::Configuration` (r:1 w:0)
+	/// Proof: `OrderCreator::Configuration` (`max_values`: Some(1), `max_size`: Some(35), added: 530, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::NextOrder` (r:1 w:1)
+	/// Proof: `OrderCreator::NextOrder` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OldestOrderId` (r:1 w:1)
+	/// Proof: `OrderCreator::OldestOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:8)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:0 w:8)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Paused` (r:1 w:0)
+	/// Proof: `OrderCreator::Paused` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::PendingRetriesDue` (r:1 w:0)
+	/// Proof: `OrderCreator::PendingRetriesDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:1 w:8)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrdersDue` (r:1 w:1)
+	/// Proof: `OrderCreator::QueuedOrdersDue` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueuedOrders` (r:9 w:8)
+	/// Proof: `OrderCreator::QueuedOrders` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::RequirementProfiles` (r:1 w:1)
+	/// Proof: `OrderCreator::RequirementProfiles` (`max_values`: Some(1), `max_size`: Some(119), added: 614, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::ScheduledChanges` (r:1 w:1)
+	/// Proof: `OrderCreator::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(109), added: 604, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn on_initialize_order(n: u32) -> Weight {
+		Weight::from_parts(31_827_625, 11_304)
+			.saturating_add(Weight::from_parts(25_020_316, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2_513).saturating_mul(n.into()))
+	}
+	/// Storage: `OrderCreator::FeeFunding` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeFunding` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::FeeMultiplier` (r:1 w:0)
+	/// Proof: `OrderCreator::FeeMultiplier` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderCallWeight` (r:1 w:0)
+	/// Proof: `OrderCreator::OrderCallWeight` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::OrderQueries` (r:0 w:1)
+	/// Proof: `OrderCreator::OrderQueries` (`max_values`: None, `max_size`: Some(46), added: 2521, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::Orders` (r:1 w:1)
+	/// Proof: `OrderCreator::Orders` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `OrderCreator::QueryExpiries` (r:0 w:1)
+	/// Proof: `OrderCreator::QueryExpiries` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn send_queued_order() -> Weight {
+		Weight::from_parts(68_026_000, 4_089)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn dispatch() -> Weight {
+		Weight::from_parts(2_972_000, 0)
+	}
+}
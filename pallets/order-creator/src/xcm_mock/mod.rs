@@ -0,0 +1,54 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! The test network of the order creator: our parachain running the order creator pallet and a
+//! mock of the RegionX parachain, next to the relay chain of `xcm_mock_network`.
+
+pub mod parachain;
+pub mod regionx;
+
+use xcm_mock_network::{execute_xcm, Para, Parachain};
+pub use xcm_mock_network::{relay, take_outcomes, take_sent_messages, Chain, Relay};
+
+/// The id of our parachain.
+pub const PARA_ID: u32 = 2001;
+/// The id of the RegionX parachain.
+pub const REGIONX_PARA_ID: u32 = 2000;
+
+/// Our parachain.
+pub type OrderCreatorPara = Para<parachain::ParachainId>;
+/// The RegionX parachain.
+pub type RegionXPara = Para<regionx::ParachainId>;
+
+/// Resets the state of all chains and drops all pending messages.
+///
+/// Our parachain keeps the reserve of its relay chain tokens on the relay chain.
+pub fn reset() {
+	xcm_mock_network::reset(
+		vec![
+			Parachain {
+				id: PARA_ID,
+				ext: parachain::new_ext(),
+				execute_xcm: execute_xcm::<parachain::XcmConfig>,
+			},
+			Parachain {
+				id: REGIONX_PARA_ID,
+				ext: regionx::new_ext(),
+				execute_xcm: execute_xcm::<regionx::XcmConfig>,
+			},
+		],
+		&[PARA_ID],
+	);
+}
@@ -0,0 +1,279 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Our parachain, sending the orders through the `DefaultOrderDispatcher`.
+//!
+//! The order fees are teleported to RegionX, or reserve transferred through the relay chain if
+//! `TeleportFees` is unset.
+
+use super::{PARA_ID, REGIONX_PARA_ID};
+use crate::{
+	mock::{CallEncoder, CoretimeParaId, DummyUsageProvider, RelayBlockNumberProvider},
+	DefaultOrderDispatcher, ReserveTransferFeeFunder, TeleportFeeFunder,
+};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, ContainsPair, Everything, Nothing},
+};
+use frame_system::EnsureRoot;
+use polkadot_parachain_primitives::primitives::Sibling;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage, DispatchResult,
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom, Case,
+	EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor,
+	FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset, SiblingParachainConvertsVia,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+};
+use xcm_executor::XcmExecutor;
+use xcm_mock_network::ParachainXcmRouter;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		PolkadotXcm: pallet_xcm,
+		OrderCreator: crate,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeTask = RuntimeTask;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxHolds = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	pub const ParachainId: u32 = PARA_ID;
+	pub const RelayLocation: MultiLocation = MultiLocation::parent();
+	pub const AnyNetwork: Option<NetworkId> = None;
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(PARA_ID));
+	pub const RegionXLocation: MultiLocation =
+		MultiLocation { parents: 1, interior: X1(Parachain(REGIONX_PARA_ID)) };
+	pub const UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	// One unit of the relay chain token per unit of `ref_time` and per byte of `proof_size`.
+	pub RelayTokenPerSecondPerMb: (AssetId, u128, u128) =
+		(Concrete(RelayLocation::get()), 1_000_000_000_000, 1024 * 1024);
+	pub RelayTokenFromRegionX: (MultiAssetFilter, MultiLocation) =
+		(Wild(AllOf { id: Concrete(RelayLocation::get()), fun: WildFungible }), RegionXLocation::get());
+}
+
+pub type LocationToAccountId = (
+	ParentIsPreset<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountId32Aliases<AnyNetwork, AccountId>,
+);
+
+/// The relay chain token is used as the native token, to keep things simple.
+pub type AssetTransactor =
+	FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+pub type OriginConverter = (
+	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+	SignedAccountId32AsNative<AnyNetwork, RuntimeOrigin>,
+);
+
+pub type Barrier = (
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<Everything>,
+	AllowKnownQueryResponses<PolkadotXcm>,
+);
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = ParachainXcmRouter<ParachainId>;
+	type AssetTransactor = AssetTransactor;
+	type OriginConverter = OriginConverter;
+	// The relay chain is the reserve of its token.
+	type IsReserve = NativeAsset;
+	type IsTeleporter = FeeTeleports;
+	type UniversalLocation = UniversalLocation;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = FixedRateOfFungible<RelayTokenPerSecondPerMb, ()>;
+	type ResponseHandler = PolkadotXcm;
+	type AssetTrap = PolkadotXcm;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+	type TransactionalProcessor = FrameTransactionalProcessor;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
+
+impl pallet_xcm::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ParachainXcmRouter<ParachainId>;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Everything;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type Currency = Balances;
+	type CurrencyMatcher = IsConcrete<RelayLocation>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+}
+
+parameter_types! {
+	// Whether the order fees are teleported to RegionX.
+	pub static TeleportFees: bool = true;
+}
+
+/// Allows teleporting the relay chain token to RegionX, unless the fees are reserve transferred.
+pub struct FeeTeleports;
+impl ContainsPair<MultiAsset, MultiLocation> for FeeTeleports {
+	fn contains(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		TeleportFees::get() && Case::<RelayTokenFromRegionX>::contains(asset, origin)
+	}
+}
+
+/// Teleports the order fees or reserve transfers them through the relay chain, depending on
+/// `TeleportFees`.
+pub struct FeeFunder;
+impl crate::FeeFunder<AccountId, Balance> for FeeFunder {
+	fn fund(source: &AccountId, amount: Balance) -> DispatchResult {
+		if TeleportFees::get() {
+			TeleportFeeFunder::<Runtime>::fund(source, amount)
+		} else {
+			ReserveTransferFeeFunder::<Runtime>::fund(source, amount)
+		}
+	}
+}
+
+/// Converts weight to fees the way RegionX charges for it: one unit of the relay chain token per
+/// unit of `ref_time` and per byte of `proof_size`.
+pub struct WeightToFee;
+impl frame_support::weights::WeightToFee for WeightToFee {
+	type Balance = Balance;
+
+	fn weight_to_fee(weight: &Weight) -> Balance {
+		(weight.ref_time() + weight.proof_size()).into()
+	}
+}
+
+impl crate::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RelaychainCurrency = Balances;
+	type RelaychainBalance = Balance;
+	type RCBlockNumberProvider = RelayBlockNumberProvider;
+	type RegionXLocation = RegionXLocation;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type OrderDispatcher = DefaultOrderDispatcher<Runtime>;
+	type XcmResponseOrigin = pallet_xcm::EnsureResponse<Everything>;
+	type FeeFunder = FeeFunder;
+	type UsageProvider = DummyUsageProvider;
+	type CoretimeParaId = CoretimeParaId;
+	type RelayStateRootProvider = ();
+	type CoretimeChain = crate::SystemCoretimeChain;
+	type MaxStateProofSize = ConstU32<4_096>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::mock::BenchmarkHelper;
+	type CallEncoder = CallEncoder;
+	type WeightToFee = WeightToFee;
+	type TimeslicePeriod = ConstU64<80>;
+	type CoretimeBlockPeriod = ConstU64<2>;
+	type MaxDispatchDelay = ConstU64<0>;
+	type MaxRetries = ConstU32<3>;
+	type RetryDelay = ConstU64<10>;
+	type MaxOrderHistory = ConstU32<10>;
+	type MaxRegionSplit = ConstU32<4>;
+	type MaxProfiles = ConstU32<2>;
+	type MaxScheduledChanges = ConstU32<2>;
+	type ResponseTimeout = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+pub fn new_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,270 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! A mock of the RegionX parachain, with an orders pallet at the index the `CallEncoder` of the
+//! mock encodes the order creation call for.
+
+use super::{PARA_ID, REGIONX_PARA_ID};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU128, ConstU32, Everything, Nothing},
+};
+use frame_system::EnsureRoot;
+use polkadot_parachain_primitives::primitives::Sibling;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowKnownQueryResponses, AllowTopLevelPaidExecutionFrom, Case,
+	EnsureXcmOrigin, FixedRateOfFungible, FixedWeightBounds, FrameTransactionalProcessor,
+	FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset, SiblingParachainConvertsVia,
+	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
+};
+use xcm_executor::XcmExecutor;
+use xcm_mock_network::ParachainXcmRouter;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+/// A minimal version of the orders pallet of RegionX, which records the created orders.
+// The code generated for the pallet under `try-runtime` trips `clippy::manual_inspect`.
+#[allow(clippy::manual_inspect)]
+#[frame_support::pallet]
+pub mod orders {
+	use crate::{OrderRequirements, ParaId};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use scale_info::prelude::vec::Vec;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// The created orders, together with the account which created them.
+	#[pallet::storage]
+	pub type Orders<T: Config> =
+		StorageValue<_, Vec<(T::AccountId, ParaId, OrderRequirements)>, ValueQuery>;
+
+	/// Whether the creation of orders is rejected.
+	#[pallet::storage]
+	pub type Closed<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An order got created.
+		OrderCreated { creator: T::AccountId, para_id: ParaId, requirements: OrderRequirements },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The creation of orders is closed.
+		OrdersClosed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::from_parts(50_000_000, 5_000))]
+		pub fn create_order(
+			origin: OriginFor<T>,
+			para_id: ParaId,
+			requirements: OrderRequirements,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			ensure!(!Closed::<T>::get(), Error::<T>::OrdersClosed);
+
+			Orders::<T>::append((creator.clone(), para_id, requirements.clone()));
+			Self::deposit_event(Event::OrderCreated { creator, para_id, requirements });
+			Ok(())
+		}
+	}
+}
+
+frame_support::construct_runtime!(
+	pub enum Runtime
+	{
+		System: frame_system = 0,
+		Balances: pallet_balances = 1,
+		PolkadotXcm: pallet_xcm = 2,
+		Orders: orders = 92,
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeTask = RuntimeTask;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxHolds = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+impl orders::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const ParachainId: u32 = REGIONX_PARA_ID;
+	/// The fee charged for delivering each message sent by RegionX.
+	pub static DeliveryFee: u128 = 0;
+	pub const RelayLocation: MultiLocation = MultiLocation::parent();
+	pub const AnyNetwork: Option<NetworkId> = None;
+	pub UniversalLocation: InteriorMultiLocation = X1(Parachain(REGIONX_PARA_ID));
+	pub const ParachainLocation: MultiLocation =
+		MultiLocation { parents: 1, interior: X1(Parachain(PARA_ID)) };
+	pub const UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+	// One unit of the relay chain token per unit of `ref_time` and per byte of `proof_size`.
+	pub RelayTokenPerSecondPerMb: (AssetId, u128, u128) =
+		(Concrete(RelayLocation::get()), 1_000_000_000_000, 1024 * 1024);
+	pub RelayTokenFromParachain: (MultiAssetFilter, MultiLocation) =
+		(Wild(AllOf { id: Concrete(RelayLocation::get()), fun: WildFungible }), ParachainLocation::get());
+}
+
+pub type LocationToAccountId = (
+	ParentIsPreset<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountId32Aliases<AnyNetwork, AccountId>,
+);
+
+/// The relay chain token is used as the native token, to keep things simple.
+pub type AssetTransactor =
+	FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+pub type OriginConverter = (
+	SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+	SignedAccountId32AsNative<AnyNetwork, RuntimeOrigin>,
+);
+
+pub type Barrier = (
+	TakeWeightCredit,
+	AllowTopLevelPaidExecutionFrom<Everything>,
+	AllowKnownQueryResponses<PolkadotXcm>,
+);
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = ParachainXcmRouter<ParachainId, DeliveryFee>;
+	type AssetTransactor = AssetTransactor;
+	type OriginConverter = OriginConverter;
+	// The relay chain is the reserve of its token.
+	type IsReserve = NativeAsset;
+	type IsTeleporter = Case<RelayTokenFromParachain>;
+	type UniversalLocation = UniversalLocation;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = FixedRateOfFungible<RelayTokenPerSecondPerMb, ()>;
+	type ResponseHandler = PolkadotXcm;
+	type AssetTrap = PolkadotXcm;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = PolkadotXcm;
+	type SubscriptionService = PolkadotXcm;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+	type TransactionalProcessor = FrameTransactionalProcessor;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
+
+impl pallet_xcm::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ParachainXcmRouter<ParachainId, DeliveryFee>;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Everything;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type TrustedLockers = ();
+	type SovereignAccountOf = LocationToAccountId;
+	type Currency = Balances;
+	type CurrencyMatcher = IsConcrete<RelayLocation>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+}
+
+pub fn new_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,373 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+	assert_ok,
+	traits::{fungible::Mutate, Hooks},
+};
+use sp_core::Get;
+use sp_runtime::{AccountId32, Perbill};
+use xcm::{latest::prelude::*, VersionedXcm};
+use xcm_executor::traits::ConvertLocation;
+
+use crate::{
+	mock::RelayBlockNumber,
+	xcm_mock::{
+		parachain, regionx, relay, reset, take_outcomes, take_sent_messages, Chain,
+		OrderCreatorPara, RegionXPara, Relay, PARA_ID, REGIONX_PARA_ID,
+	},
+	Config, ConfigRecordOf, Event, FeeFundingConfig, GenericRequirements, OrderRequirements,
+	OrderStatus, Orders, PendingRetries, ProfileName, RequirementProfile,
+};
+
+const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+
+/// The requirements of the first order made after `setup_order_creation`.
+const FIRST_ORDER: OrderRequirements =
+	OrderRequirements { begin: 2_520, end: 3_780, core_occupancy: 28_800 };
+
+fn setup_order_creation() {
+	reset();
+	configure_order_creation();
+}
+
+fn configure_order_creation() {
+	OrderCreatorPara::execute_with(|| {
+		use parachain::{OrderCreator, Runtime, RuntimeOrigin};
+
+		let configuration = ConfigRecordOf::<Runtime> {
+			advance_notice: 10,
+			interlude_length: 7_200,
+			leadin_length: 21_600,
+			region_length: 1_260,
+			ideal_bulk_proportion: Perbill::from_percent(40),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(40),
+			contribution_timeout: 1_260,
+		};
+		assert_ok!(OrderCreator::set_configuration(RuntimeOrigin::root(), configuration));
+		assert_ok!(OrderCreator::schedule_next_order(RuntimeOrigin::root(), 1_260));
+		assert_ok!(OrderCreator::set_requirement_profile(
+			RuntimeOrigin::root(),
+			ProfileName::truncate_from(b"main".to_vec()),
+			Some(RequirementProfile {
+				requirements: GenericRequirements::new(28_800),
+				cadence: 1,
+				active: true,
+			})
+		));
+
+		let timeslice_period: u64 = <Runtime as Config>::TimeslicePeriod::get();
+		RelayBlockNumber::set(1_260 * timeslice_period);
+	});
+}
+
+/// The account of our parachain on RegionX, which pays for the orders.
+fn sovereign_account() -> regionx::AccountId {
+	regionx::LocationToAccountId::convert_location(&MultiLocation::new(1, X1(Parachain(PARA_ID))))
+		.unwrap()
+}
+
+fn sovereign_balance() -> regionx::Balance {
+	RegionXPara::execute_with(|| regionx::Balances::free_balance(sovereign_account()))
+}
+
+fn fund_sovereign_account(amount: regionx::Balance) {
+	RegionXPara::execute_with(|| {
+		assert_ok!(regionx::Balances::mint_into(&sovereign_account(), amount));
+	});
+}
+
+/// The amount paid for executing a message on RegionX.
+///
+/// RegionX charges one unit per unit of `ref_time` and per byte of `proof_size`.
+fn execution_fee(weight: Weight) -> regionx::Balance {
+	(weight.ref_time() + weight.proof_size()).into()
+}
+
+#[test]
+fn order_is_created_on_regionx() {
+	setup_order_creation();
+	fund_sovereign_account(1_000_000_000);
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::OrderCreator::on_initialize(1);
+	});
+
+	// The order is executed on RegionX and the outcome is reported back.
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 2);
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(order_weight)) = outcomes[0] else {
+		panic!("the order should be executed on RegionX: {:?}", outcomes[0]);
+	};
+	assert!(matches!(outcomes[1], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+
+	RegionXPara::execute_with(|| {
+		assert_eq!(
+			regionx::orders::Orders::<regionx::Runtime>::get(),
+			vec![(sovereign_account(), PARA_ID.into(), FIRST_ORDER)]
+		);
+	});
+	// Only the weight actually used is paid for, the rest of the fee is refunded.
+	assert_eq!(sovereign_balance(), 1_000_000_000 - execution_fee(order_weight));
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::System::assert_has_event(Event::OrderAcknowledged { order_id: 0 }.into());
+		assert_eq!(
+			Orders::<parachain::Runtime>::get(0).map(|order| order.status),
+			Some(OrderStatus::Acknowledged)
+		);
+	});
+}
+
+#[test]
+fn order_fee_covers_the_whole_message() {
+	setup_order_creation();
+	let fee = OrderCreatorPara::execute_with(parachain::OrderCreator::order_fee);
+	// Nothing but the fee of the order, on top of the existential deposit.
+	fund_sovereign_account(fee + 1);
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::OrderCreator::on_initialize(1);
+	});
+
+	let outcomes = take_outcomes();
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(order_weight)) = outcomes[0] else {
+		panic!("the order should be executed on RegionX: {:?}", outcomes[0]);
+	};
+	RegionXPara::execute_with(|| {
+		assert_eq!(regionx::orders::Orders::<regionx::Runtime>::get().len(), 1);
+	});
+	assert_eq!(sovereign_balance(), fee + 1 - execution_fee(order_weight));
+}
+
+#[test]
+fn report_delivery_fee_is_paid_from_the_sovereign_account() {
+	setup_order_creation();
+	fund_sovereign_account(1_000_000_000);
+	let delivery_fee = 1_000_000;
+	regionx::DeliveryFee::set(delivery_fee);
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::OrderCreator::on_initialize(1);
+	});
+
+	// The delivery fee of the report is withdrawn from the sovereign account, so the report isn't
+	// lost for lack of fees in the holding.
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 2);
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(order_weight)) = outcomes[0] else {
+		panic!("the order should be executed on RegionX: {:?}", outcomes[0]);
+	};
+	assert!(matches!(outcomes[1], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+	assert_eq!(sovereign_balance(), 1_000_000_000 - execution_fee(order_weight) - delivery_fee);
+
+	OrderCreatorPara::execute_with(|| {
+		assert_eq!(
+			Orders::<parachain::Runtime>::get(0).map(|order| order.status),
+			Some(OrderStatus::Acknowledged)
+		);
+	});
+}
+
+#[test]
+fn query_is_dropped_when_the_order_cannot_be_sent() {
+	// RegionX isn't part of the network, so the order cannot be routed to it.
+	xcm_mock_network::reset(
+		vec![xcm_mock_network::Parachain {
+			id: PARA_ID,
+			ext: parachain::new_ext(),
+			execute_xcm: xcm_mock_network::execute_xcm::<parachain::XcmConfig>,
+		}],
+		&[PARA_ID],
+	);
+	configure_order_creation();
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::OrderCreator::on_initialize(1);
+
+		parachain::System::assert_has_event(
+			Event::OrderDispatchFailed { order_id: 0, error: SendError::NotApplicable }.into(),
+		);
+		// No response will arrive for the query registered for the order.
+		assert!(parachain::PolkadotXcm::query(0).is_none());
+	});
+}
+
+#[test]
+fn rejected_order_is_reported_back() {
+	setup_order_creation();
+	fund_sovereign_account(1_000_000_000);
+	RegionXPara::execute_with(|| regionx::orders::Closed::<regionx::Runtime>::put(true));
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::OrderCreator::on_initialize(1);
+	});
+
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 2);
+	let (
+		Chain::Para(REGIONX_PARA_ID),
+		Outcome::Incomplete(order_weight, XcmError::ExpectationFalse),
+	) = outcomes[0]
+	else {
+		panic!("the order should fail on RegionX: {:?}", outcomes[0]);
+	};
+
+	RegionXPara::execute_with(|| {
+		assert!(regionx::orders::Orders::<regionx::Runtime>::get().is_empty());
+	});
+	// The unused fee is refunded even though the order failed.
+	assert_eq!(sovereign_balance(), 1_000_000_000 - execution_fee(order_weight));
+
+	OrderCreatorPara::execute_with(|| {
+		parachain::System::assert_has_event(
+			Event::OrderRejected { order_id: 0, error: XcmError::ExpectationFalse }.into(),
+		);
+		assert_eq!(
+			Orders::<parachain::Runtime>::get(0).map(|order| order.status),
+			Some(OrderStatus::Failed)
+		);
+		assert!(PendingRetries::<parachain::Runtime>::get(0).is_some());
+	});
+}
+
+#[test]
+fn order_fee_is_teleported_to_regionx() {
+	setup_order_creation();
+	// Some funds are left over from previous orders.
+	fund_sovereign_account(100_000_000);
+
+	let fee = OrderCreatorPara::execute_with(|| {
+		use parachain::{Balances, OrderCreator, RuntimeOrigin};
+
+		assert_ok!(Balances::mint_into(&ALICE, 1_000_000_000));
+		assert_ok!(OrderCreator::set_fee_funding(
+			RuntimeOrigin::root(),
+			Some(FeeFundingConfig { source: ALICE, min_balance: 0 })
+		));
+		OrderCreator::on_initialize(1);
+
+		let fee = OrderCreator::order_fee();
+		assert_eq!(Balances::free_balance(ALICE), 1_000_000_000 - fee);
+		fee
+	});
+
+	// The fee arrives before the order and pays for it.
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 3);
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(funding_weight)) = outcomes[0] else {
+		panic!("the fee should be received by RegionX: {:?}", outcomes[0]);
+	};
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(order_weight)) = outcomes[1] else {
+		panic!("the order should be executed on RegionX: {:?}", outcomes[1]);
+	};
+	assert!(matches!(outcomes[2], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+
+	RegionXPara::execute_with(|| {
+		assert_eq!(regionx::orders::Orders::<regionx::Runtime>::get().len(), 1);
+	});
+	assert_eq!(
+		sovereign_balance(),
+		100_000_000 + fee - execution_fee(funding_weight) - execution_fee(order_weight)
+	);
+}
+
+#[test]
+fn reserve_transferred_fee_arrives_after_the_order() {
+	setup_order_creation();
+	parachain::TeleportFees::set(false);
+	// Some funds are left over from previous orders.
+	fund_sovereign_account(1_000_000_000);
+
+	let fee = OrderCreatorPara::execute_with(|| {
+		use parachain::{Balances, OrderCreator, RuntimeOrigin};
+
+		assert_ok!(Balances::mint_into(&ALICE, 1_000_000_000));
+		assert_ok!(OrderCreator::set_fee_funding(
+			RuntimeOrigin::root(),
+			Some(FeeFundingConfig { source: ALICE, min_balance: 0 })
+		));
+		OrderCreator::on_initialize(1);
+
+		let fee = OrderCreator::order_fee();
+		assert_eq!(Balances::free_balance(ALICE), 1_000_000_000 - fee);
+		fee
+	});
+
+	// The order is sent straight to RegionX, so it is paid for from the funds left over before
+	// the fee, which is withdrawn from its reserve on the relay chain, gets deposited.
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 4);
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(order_weight)) = outcomes[0] else {
+		panic!("the order should be executed on RegionX: {:?}", outcomes[0]);
+	};
+	assert!(matches!(outcomes[1], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+	let (Chain::Relay, Outcome::Complete(reserve_weight)) = outcomes[2] else {
+		panic!("the fee should be withdrawn on the relay chain: {:?}", outcomes[2]);
+	};
+	let (Chain::Para(REGIONX_PARA_ID), Outcome::Complete(deposit_weight)) = outcomes[3] else {
+		panic!("the fee should be deposited on RegionX: {:?}", outcomes[3]);
+	};
+
+	Relay::execute_with(|| {
+		assert_eq!(
+			relay::Balances::free_balance(relay::sovereign_account(PARA_ID)),
+			relay::PARACHAIN_RESERVE - fee
+		);
+		assert_eq!(
+			relay::Balances::free_balance(relay::sovereign_account(REGIONX_PARA_ID)),
+			fee - execution_fee(reserve_weight)
+		);
+	});
+
+	RegionXPara::execute_with(|| {
+		assert_eq!(regionx::orders::Orders::<regionx::Runtime>::get().len(), 1);
+	});
+	assert_eq!(
+		sovereign_balance(),
+		1_000_000_000 + fee -
+			execution_fee(order_weight) -
+			execution_fee(reserve_weight) -
+			execution_fee(deposit_weight)
+	);
+}
+
+#[test]
+fn previewed_order_message_is_the_one_sent() {
+	setup_order_creation();
+	fund_sovereign_account(1_000_000_000);
+
+	let preview = OrderCreatorPara::execute_with(|| {
+		use parachain::OrderCreator;
+
+		let preview = OrderCreator::next_order_messages().unwrap();
+		OrderCreator::on_initialize(1);
+		preview
+	});
+
+	let sent = take_sent_messages();
+	let (sender, destination, message) = &sent[0];
+	assert_eq!(*sender, Chain::Para(PARA_ID));
+	assert_eq!(*destination, Chain::Para(REGIONX_PARA_ID));
+	assert_eq!(
+		preview,
+		vec![(
+			ProfileName::truncate_from(b"main".to_vec()),
+			MultiLocation::new(1, X1(Parachain(REGIONX_PARA_ID))),
+			VersionedXcm::from(message.clone())
+		)]
+	);
+}
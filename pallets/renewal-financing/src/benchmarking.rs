@@ -0,0 +1,157 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Benchmarks for pallet-renewal-financing
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+use xcm::latest::prelude::*;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+	use frame_support::traits::EnsureOrigin;
+
+	fn setup_renewal<T: Config>(when: Timeslice, target: T::RelaychainBalance) {
+		Renewals::<T>::insert(
+			when,
+			RenewalRecord { core: 0, target, raised: 0u32.into(), status: RenewalStatus::Raising },
+		);
+	}
+
+	#[benchmark]
+	fn set_renewal_target() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let target: T::RelaychainBalance = 1_000_000u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1_260, 0, target);
+
+		assert_last_event::<T>(Event::RenewalTargetSet { when: 1_260, core: 0, target }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn contribute() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::RelaychainBalance = 1_000_000u32.into();
+		T::RelaychainCurrency::set_balance(&caller, 2_000_000u32.into());
+		setup_renewal::<T>(1_260, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 1_260, amount);
+
+		assert_last_event::<T>(Event::TargetReached { when: 1_260 }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_contribution() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::RelaychainBalance = 1_000_000u32.into();
+		T::RelaychainCurrency::set_balance(&caller, 2_000_000u32.into());
+		setup_renewal::<T>(1_260, amount);
+		Pallet::<T>::contribute(RawOrigin::Signed(caller.clone()).into(), 1_260, amount)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 1_260, amount);
+
+		assert_last_event::<T>(
+			Event::ContributionWithdrawn { when: 1_260, who: caller, amount }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_funds() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::RelaychainBalance = 1_000_000u32.into();
+		T::RelaychainCurrency::set_balance(&caller, 2_000_000u32.into());
+		setup_renewal::<T>(1_260, amount);
+		Pallet::<T>::contribute(RawOrigin::Signed(caller.clone()).into(), 1_260, amount)?;
+
+		#[extrinsic_call]
+		renew(RawOrigin::Signed(caller), 1_260);
+
+		assert!(matches!(
+			Renewals::<T>::get(1_260).map(|record| record.status),
+			Some(RenewalStatus::Transferring(_))
+		));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn renew() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: T::RelaychainBalance = 1_000_000u32.into();
+		Renewals::<T>::insert(
+			1_260,
+			RenewalRecord {
+				core: 0,
+				target: amount,
+				raised: amount,
+				status: RenewalStatus::FundsTransferred,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 1_260);
+
+		assert!(matches!(
+			Renewals::<T>::get(1_260).map(|record| record.status),
+			Some(RenewalStatus::Dispatched(_))
+		));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn notify_renewal_status() -> Result<(), BenchmarkError> {
+		// The worst case is the relay chain failing to forward the funds, which returns them to the
+		// account holding the contributions.
+		let origin = T::XcmResponseOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let amount: T::RelaychainBalance = 1_000_000u32.into();
+		Renewals::<T>::insert(
+			1_260,
+			RenewalRecord {
+				core: 0,
+				target: amount,
+				raised: amount,
+				status: RenewalStatus::Transferring(0),
+			},
+		);
+		RenewalQueries::<T>::insert(0, 1_260);
+		let response = Response::ExecutionResult(Some((0, XcmError::Unroutable)));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, response);
+
+		assert_last_event::<T>(
+			Event::TransferFailed { when: 1_260, error: XcmError::Unroutable }.into(),
+		);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(vec![]), crate::mock::Test);
+}
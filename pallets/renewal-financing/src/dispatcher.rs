@@ -0,0 +1,250 @@
+use crate::{types::CallEncoder, Error, LOG_TARGET};
+use core::marker::PhantomData;
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	storage_alias,
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision},
+	},
+	weights::WeightToFee,
+	Blake2_128Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_broker::CoreIndex;
+use scale_info::prelude::vec;
+use sp_runtime::{
+	traits::{Get, Saturating},
+	DispatchError, DispatchResult,
+};
+use xcm::latest::prelude::*;
+use xcm_executor::traits::WeightBounds;
+
+/// Type able to dispatch bulk renewals to the Coretime chain.
+pub trait RenewalDispatcher<AccountId, Balance> {
+	/// Transfers the raised `funds` from the `source` account to the parachain's sovereign account
+	/// on the Coretime chain.
+	///
+	/// Returns the id of the query through which the relay chain reports whether it forwarded the
+	/// funds to the Coretime chain.
+	fn transfer_funds(source: &AccountId, funds: Balance) -> Result<QueryId, DispatchError>;
+
+	/// Renews the specified `core`, paying for it from the parachain's sovereign account on the
+	/// Coretime chain.
+	///
+	/// Returns the id of the query through which the Coretime chain reports the outcome of the
+	/// renewal.
+	fn renew(core: CoreIndex) -> Result<QueryId, DispatchError>;
+}
+
+/// The queries registered with `pallet_xcm`, which doesn't provide a way of dropping a query
+/// whose message couldn't be sent.
+#[storage_alias]
+type Queries<T: pallet_xcm::Config> = StorageMap<
+	pallet_xcm::Pallet<T>,
+	Blake2_128Concat,
+	QueryId,
+	pallet_xcm::QueryStatus<BlockNumberFor<T>>,
+>;
+
+/// Transfers the raised funds to the parachain's sovereign account on the Coretime chain through
+/// the relay chain, which is their reserve, and renews the core from there.
+///
+/// The funds are burnt here and withdrawn from our sovereign account on the relay chain by a
+/// message sent straight to it, so that the relay chain can report back once it forwarded them.
+/// A renewal sent to the Coretime chain at the same time as the funds would arrive before them,
+/// and it would fail before being able to report its outcome. The renewal therefore has to wait
+/// for the report of the relay chain.
+///
+/// The execution fees of the transfer are paid from the transferred funds, while the fee of the
+/// renewal is paid from the sovereign account. The renewal target should cover both on top of the
+/// renewal price.
+pub struct DefaultRenewalDispatcher<T: crate::Config + pallet_xcm::Config>(PhantomData<T>);
+impl<T: crate::Config + pallet_xcm::Config> RenewalDispatcher<T::AccountId, T::RelaychainBalance>
+	for DefaultRenewalDispatcher<T>
+where
+	<T as pallet_xcm::Config>::RuntimeCall: From<crate::Call<T>>,
+{
+	fn transfer_funds(
+		source: &T::AccountId,
+		funds: T::RelaychainBalance,
+	) -> Result<QueryId, DispatchError> {
+		let relay = MultiLocation::parent();
+		let coretime = <T as crate::Config>::CoretimeChainLocation::get();
+		let coretime_from_relay = coretime
+			.reanchored(&relay, <T as pallet_xcm::Config>::UniversalLocation::get())
+			.map_err(|_| Error::<T>::RenewalDispatchFailed)?;
+		let sovereign_account = location_on::<T>(&coretime)?;
+
+		T::RelaychainCurrency::burn_from(source, funds, Precision::Exact, Fortitude::Polite)?;
+
+		let report = new_report::<T>(relay)?;
+		let query_id = report.query_id;
+		let message =
+			transfer_message(funds.into(), coretime_from_relay, sovereign_account, report);
+		send::<T>(relay, message, query_id)?;
+
+		log::info!(
+			target: LOG_TARGET,
+			"Renewal funds sent successfully"
+		);
+		Ok(query_id)
+	}
+
+	fn renew(core: CoreIndex) -> Result<QueryId, DispatchError> {
+		let destination = <T as crate::Config>::CoretimeChainLocation::get();
+		let report = new_report::<T>(destination)?;
+		let query_id = report.query_id;
+
+		// The fee has to cover the execution of the whole message, not only of the call.
+		let mut weighed = renewal_message::<T>(core, 0, report.clone()).into();
+		let weight = <T as pallet_xcm::Config>::Weigher::weight(&mut weighed)
+			.map_err(|()| Error::<T>::RenewalDispatchFailed)?;
+		let fee = T::WeightToFee::weight_to_fee(&weight);
+		let message = renewal_message::<T>(core, fee.into(), report);
+		send::<T>(destination, message, query_id)?;
+
+		log::info!(
+			target: LOG_TARGET,
+			"Renewal of core {} sent successfully",
+			core
+		);
+		Ok(query_id)
+	}
+}
+
+/// Returns our location as seen from `destination`.
+fn location_on<T: crate::Config + pallet_xcm::Config>(
+	destination: &MultiLocation,
+) -> Result<MultiLocation, DispatchError> {
+	<T as pallet_xcm::Config>::UniversalLocation::get()
+		.invert_target(destination)
+		.map_err(|()| Error::<T>::RenewalDispatchFailed.into())
+}
+
+/// Registers a query through which `responder` reports back to `notify_renewal_status`.
+fn new_report<T: crate::Config + pallet_xcm::Config>(
+	responder: MultiLocation,
+) -> Result<QueryResponseInfo, DispatchError>
+where
+	<T as pallet_xcm::Config>::RuntimeCall: From<crate::Call<T>>,
+{
+	let destination = location_on::<T>(&responder)?;
+
+	// Only the call index matters, the arguments are filled in by `pallet_xcm` once the response
+	// arrives.
+	let notify: <T as pallet_xcm::Config>::RuntimeCall = crate::Call::<T>::notify_renewal_status {
+		query_id: Default::default(),
+		response: Default::default(),
+	}
+	.into();
+	let max_weight = notify.get_dispatch_info().weight;
+	let timeout = frame_system::Pallet::<T>::block_number()
+		.saturating_add(<T as crate::Config>::ResponseTimeout::get());
+	let query_id = pallet_xcm::Pallet::<T>::new_notify_query(responder, notify, timeout, Here);
+
+	Ok(QueryResponseInfo { destination, query_id, max_weight })
+}
+
+/// Sends `message` to `destination`, dropping the query through which it reports back if it
+/// couldn't be sent.
+fn send<T: crate::Config + pallet_xcm::Config>(
+	destination: MultiLocation,
+	message: Xcm<()>,
+	query_id: QueryId,
+) -> DispatchResult {
+	if let Err(e) = pallet_xcm::Pallet::<T>::send_xcm(Here, destination, message) {
+		log::error!(
+			target: LOG_TARGET,
+			"Failed to send a message to {:?}: {:?}",
+			destination,
+			e
+		);
+		// No response will ever arrive.
+		Queries::<T>::remove(query_id);
+		return Err(Error::<T>::RenewalDispatchFailed.into());
+	}
+	Ok(())
+}
+
+/// Returns the message withdrawing `funds` from our sovereign account on the relay chain and
+/// depositing them into `sovereign_account` on the Coretime chain, which is located at
+/// `coretime` as seen from the relay chain.
+///
+/// Half of the funds are available for paying the execution on each chain, the same way
+/// `pallet_xcm` splits the fees of a reserve transfer.
+fn transfer_message(
+	funds: u128,
+	coretime: MultiLocation,
+	sovereign_account: MultiLocation,
+	report: QueryResponseInfo,
+) -> Xcm<()> {
+	let relay_fee = funds.saturating_div(2);
+	let coretime_fee = funds.saturating_sub(relay_fee);
+	let beneficiary = report.destination;
+
+	Xcm(vec![
+		Instruction::WithdrawAsset((Here, funds).into()),
+		Instruction::BuyExecution { fees: (Here, relay_fee).into(), weight_limit: Unlimited },
+		// The delivery fees are withdrawn from our sovereign account when the messages are sent,
+		// instead of from the funds.
+		Instruction::SetFeesMode { jit_withdraw: true },
+		// Report whether the funds were forwarded, returning them to our sovereign account if
+		// they weren't.
+		Instruction::SetAppendix(Xcm(vec![
+			Instruction::ReportError(report),
+			Instruction::RefundSurplus,
+			Instruction::DepositAsset { assets: AllCounted(1).into(), beneficiary },
+		])),
+		Instruction::DepositReserveAsset {
+			assets: AllCounted(1).into(),
+			dest: coretime,
+			xcm: Xcm(vec![
+				Instruction::BuyExecution {
+					fees: (Parent, coretime_fee).into(),
+					weight_limit: Unlimited,
+				},
+				Instruction::DepositAsset {
+					assets: AllCounted(1).into(),
+					beneficiary: sovereign_account,
+				},
+			]),
+		},
+	])
+}
+
+/// Returns the message renewing `core` on the Coretime chain, paying `fee` for its execution
+/// from the sovereign account and reporting the outcome through `report`.
+fn renewal_message<T: crate::Config>(
+	core: CoreIndex,
+	fee: u128,
+	report: QueryResponseInfo,
+) -> Xcm<()> {
+	let fee_asset = MultiAsset { id: Concrete(MultiLocation::parent()), fun: Fungible(fee) };
+	let sovereign_account = report.destination;
+
+	Xcm(vec![
+		Instruction::WithdrawAsset(fee_asset.clone().into()),
+		Instruction::BuyExecution { fees: fee_asset, weight_limit: Unlimited },
+		// The delivery fee of the report is withdrawn from the sovereign account when the report
+		// is sent, instead of from the fees bought for the execution.
+		Instruction::SetFeesMode { jit_withdraw: true },
+		// Report the outcome of the renewal and return the unused fees to the sovereign account,
+		// even if the `Transact` fails.
+		Instruction::SetAppendix(Xcm(vec![
+			Instruction::ReportError(report),
+			Instruction::RefundSurplus,
+			Instruction::DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: sovereign_account,
+			},
+		])),
+		// The renewal price is charged from the sovereign account.
+		Instruction::Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			require_weight_at_most: T::RenewalCallWeight::get(),
+			call: T::CallEncoder::renewal_call(core).into(),
+		},
+		Instruction::ExpectTransactStatus(MaybeErrorCode::Success),
+	])
+}
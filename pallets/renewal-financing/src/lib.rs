@@ -1 +1,484 @@
+//! Renewal financing pallet.
+//!
+//! Allows anyone to contribute relay chain tokens towards the bulk coretime renewal of the
+//! parachain. Once the target amount of a renewal is raised, anyone can transfer the raised funds
+//! to the Coretime chain and, once the relay chain reports that it forwarded them, dispatch the
+//! renewal, which can be sent again whenever the Coretime chain reports that it failed.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::Weight, PalletId};
+pub use pallet::*;
+use pallet_broker::{CoreIndex, Timeslice};
+use sp_runtime::traits::AccountIdConversion;
+
+mod types;
+pub use crate::types::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod xcm_mock;
+
+#[cfg(test)]
+mod xcm_tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod dispatcher;
+pub use crate::dispatcher::*;
+
+const LOG_TARGET: &str = "runtime::renewal-financing";
+
+pub trait WeightInfo {
+	fn set_renewal_target() -> Weight;
+	fn contribute() -> Weight;
+	fn withdraw_contribution() -> Weight;
+	fn transfer_funds() -> Weight;
+	fn renew() -> Weight;
+	fn notify_renewal_status() -> Weight;
+}
+
+pub struct TestWeightInfo;
+impl WeightInfo for TestWeightInfo {
+	fn set_renewal_target() -> Weight {
+		Default::default()
+	}
+	fn contribute() -> Weight {
+		Default::default()
+	}
+	fn withdraw_contribution() -> Weight {
+		Default::default()
+	}
+	fn transfer_funds() -> Weight {
+		Default::default()
+	}
+	fn renew() -> Weight {
+		Default::default()
+	}
+	fn notify_renewal_status() -> Weight {
+		Default::default()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::{Balance, Preservation},
+			Get,
+		},
+		weights::WeightToFee,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+	use xcm::latest::prelude::*;
+
+	/// The module configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The relay chain currency in which the renewals are financed.
+		type RelaychainCurrency: Mutate<Self::AccountId, Balance = Self::RelaychainBalance>;
+
+		/// Relay chain balance type
+		type RelaychainBalance: Balance + Into<u128>;
+
+		/// The Coretime chain location to which the renewals are sent.
+		type CoretimeChainLocation: Get<MultiLocation>;
+
+		/// The admin origin for managing the renewal targets.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Type responsible for dispatching the renewals to the Coretime chain.
+		type RenewalDispatcher: RenewalDispatcher<Self::AccountId, Self::RelaychainBalance>;
+
+		/// The origin through which the outcome of the renewals is reported back.
+		///
+		/// This should be the response origin of `pallet_xcm`. Only the responses coming from
+		/// `CoretimeChainLocation` are accepted.
+		type XcmResponseOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = MultiLocation>;
+
+		/// Type which will return the scale encoded call for renewing a core.
+		type CallEncoder: CallEncoder;
+
+		/// Type for weight to fee conversion on the Coretime chain.
+		type WeightToFee: WeightToFee<Balance = Self::RelaychainBalance>;
+
+		/// The weight of the renewal call on the Coretime chain.
+		#[pallet::constant]
+		type RenewalCallWeight: Get<Weight>;
+
+		/// The pallet id used for deriving the account holding the contributions.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The number of blocks within which the relay chain and the Coretime chain are expected
+		/// to report the outcome of the transfers and renewals.
+		#[pallet::constant]
+		type ResponseTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Weight Info
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The renewals which are being financed.
+	///
+	/// Indexed by the timeslice at which the renewed region begins.
+	#[pallet::storage]
+	#[pallet::getter(fn renewals)]
+	pub type Renewals<T: Config> =
+		StorageMap<_, Twox64Concat, Timeslice, RenewalRecord<T::RelaychainBalance>, OptionQuery>;
+
+	/// The contributions made towards a renewal by each account.
+	#[pallet::storage]
+	#[pallet::getter(fn contributions)]
+	pub type Contributions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Timeslice,
+		Blake2_128Concat,
+		T::AccountId,
+		T::RelaychainBalance,
+		ValueQuery,
+	>;
+
+	/// The renewals waiting for the relay chain or the Coretime chain to report the outcome of
+	/// their transfer or renewal, indexed by the id of the query through which it is reported.
+	#[pallet::storage]
+	pub type RenewalQueries<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, Timeslice, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The renewal target for the specified region got set.
+		RenewalTargetSet { when: Timeslice, core: CoreIndex, target: T::RelaychainBalance },
+		/// A contribution was made towards a renewal.
+		Contributed { when: Timeslice, who: T::AccountId, amount: T::RelaychainBalance },
+		/// A contribution was withdrawn before the renewal got dispatched.
+		ContributionWithdrawn { when: Timeslice, who: T::AccountId, amount: T::RelaychainBalance },
+		/// The target amount of a renewal was raised.
+		TargetReached { when: Timeslice },
+		/// The raised funds were sent to the relay chain, to be forwarded to the parachain's
+		/// sovereign account on the Coretime chain.
+		FundsSent { when: Timeslice, funds: T::RelaychainBalance, query_id: QueryId },
+		/// The relay chain reported that it forwarded the raised funds to the Coretime chain.
+		FundsTransferred { when: Timeslice },
+		/// The relay chain reported that it failed to forward the raised funds. The funds are
+		/// available for raising the renewal again.
+		TransferFailed { when: Timeslice, error: XcmError },
+		/// The renewal was sent to the Coretime chain.
+		RenewalDispatched { when: Timeslice, core: CoreIndex, query_id: QueryId },
+		/// The Coretime chain reported that the core got renewed.
+		Renewed { when: Timeslice },
+		/// The Coretime chain reported that the renewal failed. The renewal can be sent again.
+		RenewalFailed { when: Timeslice, error: XcmError },
+	}
+
+	#[pallet::error]
+	#[derive(PartialEq)]
+	pub enum Error<T> {
+		/// There is no renewal being financed for the specified region.
+		UnknownRenewal,
+		/// The raised funds were already sent to the Coretime chain.
+		AlreadyDispatched,
+		/// The core was already renewed.
+		AlreadyRenewed,
+		/// The outcome of the previously sent transfer or renewal hasn't been reported yet. A
+		/// renewal might still renew the core, so it cannot be sent again.
+		AwaitingResponse,
+		/// The renewal target cannot be zero.
+		InvalidTarget,
+		/// The contribution amount cannot be zero.
+		InvalidAmount,
+		/// The contribution would leave less than the existential deposit in the account holding
+		/// the contributions.
+		ContributionBelowMinimum,
+		/// The caller contributed less than what they are trying to withdraw.
+		InsufficientContribution,
+		/// Not enough funds were raised to dispatch the renewal.
+		TargetNotReached,
+		/// Failed to send the renewal to the Coretime chain.
+		RenewalDispatchFailed,
+		/// There is no renewal waiting for a response to the specified query.
+		UnknownQuery,
+		/// The response doesn't report the outcome of a renewal.
+		UnexpectedResponse,
+		/// The response doesn't come from the chain which was expected to report it.
+		UnexpectedResponder,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the target amount which needs to be raised for renewing a region.
+		///
+		/// Can be called again to adjust the target as long as the funds haven't been transferred.
+		///
+		/// - `origin`: Must be Root or pass `AdminOrigin`.
+		/// - `when`: The timeslice at which the renewed region begins.
+		/// - `core`: The core on the Coretime chain which should be renewed.
+		/// - `target`: The amount which needs to be raised for the renewal.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_renewal_target())]
+		pub fn set_renewal_target(
+			origin: OriginFor<T>,
+			when: Timeslice,
+			core: CoreIndex,
+			target: T::RelaychainBalance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin_or_root(origin)?;
+			ensure!(!target.is_zero(), Error::<T>::InvalidTarget);
+
+			Renewals::<T>::try_mutate(when, |maybe_record| -> DispatchResult {
+				match maybe_record {
+					Some(record) => {
+						ensure!(
+							record.status == RenewalStatus::Raising,
+							Error::<T>::AlreadyDispatched
+						);
+						record.core = core;
+						record.target = target;
+					},
+					None =>
+						*maybe_record = Some(RenewalRecord {
+							core,
+							target,
+							raised: Zero::zero(),
+							status: RenewalStatus::Raising,
+						}),
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::RenewalTargetSet { when, core, target });
+			Ok(())
+		}
+
+		/// Contribute towards the renewal of a region.
+		///
+		/// - `origin`: Signed origin of the contributor.
+		/// - `when`: The timeslice at which the renewed region begins.
+		/// - `amount`: The amount to contribute.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::contribute())]
+		pub fn contribute(
+			origin: OriginFor<T>,
+			when: Timeslice,
+			amount: T::RelaychainBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			let mut record = Renewals::<T>::get(when).ok_or(Error::<T>::UnknownRenewal)?;
+			ensure!(record.status == RenewalStatus::Raising, Error::<T>::AlreadyDispatched);
+
+			let account_id = Self::account_id();
+			ensure!(
+				T::RelaychainCurrency::balance(&account_id).saturating_add(amount) >=
+					T::RelaychainCurrency::minimum_balance(),
+				Error::<T>::ContributionBelowMinimum
+			);
+
+			T::RelaychainCurrency::transfer(&who, &account_id, amount, Preservation::Preserve)?;
+
+			let target_reached_before = record.raised >= record.target;
+			record.raised.saturating_accrue(amount);
+			Contributions::<T>::mutate(when, &who, |contribution| {
+				contribution.saturating_accrue(amount)
+			});
+
+			Self::deposit_event(Event::Contributed { when, who, amount });
+			if !target_reached_before && record.raised >= record.target {
+				Self::deposit_event(Event::TargetReached { when });
+			}
+
+			Renewals::<T>::insert(when, record);
+			Ok(())
+		}
+
+		/// Withdraw a contribution made towards a renewal whose funds haven't been transferred yet.
+		///
+		/// - `origin`: Signed origin of the contributor.
+		/// - `when`: The timeslice at which the renewed region begins.
+		/// - `amount`: The amount to withdraw.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw_contribution())]
+		pub fn withdraw_contribution(
+			origin: OriginFor<T>,
+			when: Timeslice,
+			amount: T::RelaychainBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			let mut record = Renewals::<T>::get(when).ok_or(Error::<T>::UnknownRenewal)?;
+			ensure!(record.status == RenewalStatus::Raising, Error::<T>::AlreadyDispatched);
+
+			let contribution = Contributions::<T>::get(when, &who);
+			ensure!(contribution >= amount, Error::<T>::InsufficientContribution);
+
+			T::RelaychainCurrency::transfer(
+				&Self::account_id(),
+				&who,
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			record.raised.saturating_reduce(amount);
+			Renewals::<T>::insert(when, record);
+
+			let remaining = contribution.saturating_sub(amount);
+			if remaining.is_zero() {
+				Contributions::<T>::remove(when, &who);
+			} else {
+				Contributions::<T>::insert(when, &who, remaining);
+			}
+
+			Self::deposit_event(Event::ContributionWithdrawn { when, who, amount });
+			Ok(())
+		}
+
+		/// Move the renewal forward once its target has been reached.
+		///
+		/// The first call transfers the raised funds to the parachain's sovereign account on the
+		/// Coretime chain through the relay chain. Once the relay chain reported that it forwarded
+		/// them, the following call sends the renewal, which is paid for from the sovereign
+		/// account. The renewal can be sent again once the Coretime chain reported that it failed.
+		///
+		/// Callable by anyone.
+		///
+		/// - `origin`: Signed origin.
+		/// - `when`: The timeslice at which the renewed region begins.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::transfer_funds().max(T::WeightInfo::renew()))]
+		pub fn renew(origin: OriginFor<T>, when: Timeslice) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			let mut record = Renewals::<T>::get(when).ok_or(Error::<T>::UnknownRenewal)?;
+			match record.status {
+				RenewalStatus::Raising => {
+					ensure!(record.raised >= record.target, Error::<T>::TargetNotReached);
+					let query_id =
+						T::RenewalDispatcher::transfer_funds(&Self::account_id(), record.raised)?;
+					RenewalQueries::<T>::insert(query_id, when);
+
+					record.status = RenewalStatus::Transferring(query_id);
+					Self::deposit_event(Event::FundsSent { when, funds: record.raised, query_id });
+				},
+				RenewalStatus::Transferring(_) | RenewalStatus::Dispatched(_) =>
+					return Err(Error::<T>::AwaitingResponse.into()),
+				RenewalStatus::Renewed => return Err(Error::<T>::AlreadyRenewed.into()),
+				RenewalStatus::FundsTransferred | RenewalStatus::Failed => {
+					let query_id = T::RenewalDispatcher::renew(record.core)?;
+					RenewalQueries::<T>::insert(query_id, when);
+
+					record.status = RenewalStatus::Dispatched(query_id);
+					Self::deposit_event(Event::RenewalDispatched {
+						when,
+						core: record.core,
+						query_id,
+					});
+				},
+			}
+
+			Renewals::<T>::insert(when, record);
+			Ok(())
+		}
+
+		/// Record the outcome of a transfer reported by the relay chain or of a renewal reported
+		/// by the Coretime chain.
+		///
+		/// If the relay chain failed to forward the funds, they are returned to the account
+		/// holding the contributions and the renewal goes back to being raised.
+		///
+		/// - `origin`: Must pass `XcmResponseOrigin`.
+		/// - `query_id`: The id of the query registered when the funds or the renewal were sent.
+		/// - `response`: The outcome of the transfer or of the renewal.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::notify_renewal_status())]
+		pub fn notify_renewal_status(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResult {
+			let responder = T::XcmResponseOrigin::ensure_origin(origin)?;
+
+			let when = RenewalQueries::<T>::get(query_id).ok_or(Error::<T>::UnknownQuery)?;
+			let mut record = Renewals::<T>::get(when).ok_or(Error::<T>::UnknownRenewal)?;
+			let Response::ExecutionResult(outcome) = response else {
+				return Err(Error::<T>::UnexpectedResponse.into());
+			};
+
+			match record.status {
+				RenewalStatus::Transferring(_) => {
+					ensure!(responder == MultiLocation::parent(), Error::<T>::UnexpectedResponder);
+					match outcome {
+						None => {
+							record.status = RenewalStatus::FundsTransferred;
+							Self::deposit_event(Event::FundsTransferred { when });
+						},
+						Some((_, error)) => {
+							log::error!(
+								target: LOG_TARGET,
+								"Transfer of the funds for renewing core {} failed: {:?}",
+								record.core,
+								error
+							);
+							// The funds were returned to our sovereign account on the relay chain.
+							T::RelaychainCurrency::mint_into(&Self::account_id(), record.raised)?;
+							record.status = RenewalStatus::Raising;
+							Self::deposit_event(Event::TransferFailed { when, error });
+						},
+					}
+				},
+				RenewalStatus::Dispatched(_) => {
+					ensure!(
+						responder == T::CoretimeChainLocation::get(),
+						Error::<T>::UnexpectedResponder
+					);
+					match outcome {
+						None => {
+							record.status = RenewalStatus::Renewed;
+							Self::deposit_event(Event::Renewed { when });
+						},
+						Some((_, error)) => {
+							log::error!(
+								target: LOG_TARGET,
+								"Renewal of core {} failed: {:?}",
+								record.core,
+								error
+							);
+							record.status = RenewalStatus::Failed;
+							Self::deposit_event(Event::RenewalFailed { when, error });
+						},
+					}
+				},
+				_ => return Err(Error::<T>::UnknownQuery.into()),
+			}
+
+			RenewalQueries::<T>::remove(query_id);
+			Renewals::<T>::insert(when, record);
+			Ok(())
+		}
+	}
+	impl<T: Config> Pallet<T> {
+		/// The account holding the contributions.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+	}
+}
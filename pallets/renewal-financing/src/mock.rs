@@ -0,0 +1,209 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::RenewalDispatcher;
+use frame_support::{
+	pallet_prelude::*,
+	parameter_types,
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision},
+		ConstU64, Everything,
+	},
+	weights::{IdentityFee, Weight},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use pallet_broker::CoreIndex;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError,
+};
+use xcm::latest::prelude::*;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+type AccountId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Balances: pallet_balances,
+		RenewalFinancing: crate::{Pallet, Call, Storage, Event<T>}
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+	pub static ExistentialDeposit: u64 = 1;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeTask = RuntimeTask;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxHolds = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	// The location of the Coretime chain.
+	pub const CoretimeChainLocation: MultiLocation = MultiLocation { parents: 1, interior: X1(Parachain(1005)) };
+	pub const RenewalCallWeight: Weight = Weight::from_parts(100_000_000, 10_000);
+	pub const RenewalFinancingPalletId: PalletId = PalletId(*b"rx/renew");
+	pub static TransferredFunds: Vec<u64> = vec![];
+	pub static DispatchedRenewals: Vec<CoreIndex> = vec![];
+	pub static NextQueryId: QueryId = 0;
+}
+
+#[derive(Encode, Decode)]
+enum CoretimeRuntimeCalls {
+	#[codec(index = 50)]
+	Broker(BrokerPalletCalls),
+}
+
+/// Coretime chain broker pallet calls.
+//
+// NOTE: We only use the `Renew` call.
+#[derive(Encode, Decode)]
+enum BrokerPalletCalls {
+	#[codec(index = 6)]
+	Renew(CoreIndex),
+}
+
+pub struct CallEncoder;
+impl crate::CallEncoder for CallEncoder {
+	fn renewal_call(core: CoreIndex) -> Vec<u8> {
+		CoretimeRuntimeCalls::Broker(BrokerPalletCalls::Renew(core)).encode()
+	}
+}
+
+pub struct DummyRenewalDispatcher;
+impl RenewalDispatcher<AccountId, u64> for DummyRenewalDispatcher {
+	fn transfer_funds(source: &AccountId, funds: u64) -> Result<QueryId, DispatchError> {
+		// The funds leave the parachain.
+		<Balances as Mutate<AccountId>>::burn_from(
+			source,
+			funds,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		TransferredFunds::mutate(|transfers| transfers.push(funds));
+		Ok(new_query_id())
+	}
+
+	fn renew(core: CoreIndex) -> Result<QueryId, DispatchError> {
+		DispatchedRenewals::mutate(|renewals| renewals.push(core));
+		Ok(new_query_id())
+	}
+}
+
+fn new_query_id() -> QueryId {
+	NextQueryId::mutate(|id| {
+		*id += 1;
+		*id - 1
+	})
+}
+
+/// The account whose signed origin is treated as a response from the relay chain.
+pub const RELAY: AccountId = 0;
+
+/// Treats signed origins as responses from the relay chain, or from the parachain with the same
+/// id as the account.
+pub struct EnsureParachainResponse;
+impl EnsureOrigin<RuntimeOrigin> for EnsureParachainResponse {
+	type Success = MultiLocation;
+
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		let who = frame_system::EnsureSigned::<AccountId>::try_origin(o)?;
+		Ok(match who {
+			RELAY => MultiLocation::parent(),
+			para_id => MultiLocation { parents: 1, interior: X1(Parachain(para_id as u32)) },
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(RuntimeOrigin::signed(RELAY))
+	}
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RelaychainCurrency = Balances;
+	type RelaychainBalance = u64;
+	type CoretimeChainLocation = CoretimeChainLocation;
+	type AdminOrigin = EnsureRoot<<Test as frame_system::Config>::AccountId>;
+	type RenewalDispatcher = DummyRenewalDispatcher;
+	type XcmResponseOrigin = EnsureParachainResponse;
+	type CallEncoder = CallEncoder;
+	type WeightToFee = IdentityFee<u64>;
+	type RenewalCallWeight = RenewalCallWeight;
+	type PalletId = RenewalFinancingPalletId;
+	type ResponseTimeout = ConstU64<100>;
+	type WeightInfo = crate::TestWeightInfo;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext(endowed_accounts: Vec<(AccountId, u64)>) -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: endowed_accounts }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,365 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use sp_runtime::{traits::BadOrigin, TokenError};
+
+use xcm::latest::prelude::*;
+
+use crate::{
+	mock::*, Contributions, Error, Event, RenewalQueries, RenewalRecord, RenewalStatus, Renewals,
+};
+
+#[test]
+fn set_renewal_target_works() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert!(Renewals::<Test>::get(1_260).is_none());
+
+		// Failure: Bad origin
+		assert_noop!(
+			RenewalFinancing::set_renewal_target(RuntimeOrigin::signed(ALICE), 1_260, 5, 1_000),
+			BadOrigin
+		);
+
+		// Failure: Zero target
+		assert_noop!(
+			RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 0),
+			Error::<Test>::InvalidTarget
+		);
+
+		// Should be working fine
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+
+		// Check the storage item
+		assert_eq!(
+			Renewals::<Test>::get(1_260),
+			Some(RenewalRecord {
+				core: 5,
+				target: 1_000,
+				raised: 0,
+				status: RenewalStatus::Raising
+			})
+		);
+
+		// Check the emitted events
+		System::assert_last_event(
+			Event::RenewalTargetSet { when: 1_260, core: 5, target: 1_000 }.into(),
+		);
+
+		// Updating the target keeps the raised amount.
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 6, 2_000));
+		assert_eq!(
+			Renewals::<Test>::get(1_260),
+			Some(RenewalRecord {
+				core: 6,
+				target: 2_000,
+				raised: 0,
+				status: RenewalStatus::Raising
+			})
+		);
+	});
+}
+
+#[test]
+fn contribute_works() {
+	new_test_ext(vec![(ALICE, 1_000), (BOB, 1_000)]).execute_with(|| {
+		// Failure: Unknown renewal
+		assert_noop!(
+			RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 100),
+			Error::<Test>::UnknownRenewal
+		);
+
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+
+		// Failure: Zero amount
+		assert_noop!(
+			RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 0),
+			Error::<Test>::InvalidAmount
+		);
+
+		// Failure: Insufficient balance
+		assert_noop!(
+			RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 1_000),
+			TokenError::NotExpendable
+		);
+
+		// Should be working fine
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 600));
+		System::assert_last_event(
+			Event::Contributed { when: 1_260, who: ALICE, amount: 600 }.into(),
+		);
+
+		assert_eq!(Contributions::<Test>::get(1_260, ALICE), 600);
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.raised), Some(600));
+		assert_eq!(Balances::balance(&RenewalFinancing::account_id()), 600);
+
+		// Reaching the target is signalled.
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(BOB), 1_260, 400));
+		System::assert_has_event(Event::Contributed { when: 1_260, who: BOB, amount: 400 }.into());
+		System::assert_last_event(Event::TargetReached { when: 1_260 }.into());
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.raised), Some(1_000));
+	});
+}
+
+#[test]
+fn contribution_below_existential_deposit_fails() {
+	ExistentialDeposit::set(10);
+	new_test_ext(vec![(ALICE, 1_000), (BOB, 1_000)]).execute_with(|| {
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+
+		// Failure: The account holding the contributions doesn't exist yet and the contribution
+		// doesn't cover the existential deposit.
+		assert_noop!(
+			RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 9),
+			Error::<Test>::ContributionBelowMinimum
+		);
+
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 10));
+		assert_eq!(Balances::balance(&RenewalFinancing::account_id()), 10);
+
+		// Once the account exists, smaller contributions are fine.
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(BOB), 1_260, 1));
+		assert_eq!(Contributions::<Test>::get(1_260, BOB), 1);
+		assert_eq!(Balances::balance(&RenewalFinancing::account_id()), 11);
+	});
+}
+
+#[test]
+fn withdraw_contribution_works() {
+	new_test_ext(vec![(ALICE, 1_000)]).execute_with(|| {
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 600));
+
+		// Failure: Withdrawing more than contributed
+		assert_noop!(
+			RenewalFinancing::withdraw_contribution(RuntimeOrigin::signed(ALICE), 1_260, 601),
+			Error::<Test>::InsufficientContribution
+		);
+
+		// Should be working fine
+		assert_ok!(RenewalFinancing::withdraw_contribution(
+			RuntimeOrigin::signed(ALICE),
+			1_260,
+			200
+		));
+		System::assert_last_event(
+			Event::ContributionWithdrawn { when: 1_260, who: ALICE, amount: 200 }.into(),
+		);
+		assert_eq!(Contributions::<Test>::get(1_260, ALICE), 400);
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.raised), Some(400));
+		assert_eq!(Balances::balance(&ALICE), 600);
+
+		// Withdrawing everything cleans up the contribution.
+		assert_ok!(RenewalFinancing::withdraw_contribution(
+			RuntimeOrigin::signed(ALICE),
+			1_260,
+			400
+		));
+		assert!(!Contributions::<Test>::contains_key(1_260, ALICE));
+		assert_eq!(Balances::balance(&ALICE), 1_000);
+	});
+}
+
+#[test]
+fn renew_works() {
+	new_test_ext(vec![(ALICE, 2_000)]).execute_with(|| {
+		// Failure: Unknown renewal
+		assert_noop!(
+			RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260),
+			Error::<Test>::UnknownRenewal
+		);
+
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 600));
+
+		// Failure: Target not reached
+		assert_noop!(
+			RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260),
+			Error::<Test>::TargetNotReached
+		);
+
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 500));
+
+		// Sends the funds
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+		System::assert_last_event(
+			Event::FundsSent { when: 1_260, funds: 1_100, query_id: 0 }.into(),
+		);
+		assert_eq!(TransferredFunds::get(), vec![1_100]);
+		assert_eq!(
+			Renewals::<Test>::get(1_260).map(|r| r.status),
+			Some(RenewalStatus::Transferring(0))
+		);
+		assert_eq!(RenewalQueries::<Test>::get(0), Some(1_260));
+		// The funds left the parachain.
+		assert_eq!(Balances::balance(&RenewalFinancing::account_id()), 0);
+
+		// Failure: Already dispatched
+		assert_noop!(
+			RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 100),
+			Error::<Test>::AlreadyDispatched
+		);
+		assert_noop!(
+			RenewalFinancing::withdraw_contribution(RuntimeOrigin::signed(ALICE), 1_260, 100),
+			Error::<Test>::AlreadyDispatched
+		);
+		assert_noop!(
+			RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 2_000),
+			Error::<Test>::AlreadyDispatched
+		);
+
+		// Failure: The relay chain hasn't reported that it forwarded the funds.
+		assert_noop!(
+			RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260),
+			Error::<Test>::AwaitingResponse
+		);
+
+		assert_ok!(RenewalFinancing::notify_renewal_status(
+			RuntimeOrigin::signed(RELAY),
+			0,
+			Response::ExecutionResult(None)
+		));
+		System::assert_last_event(Event::FundsTransferred { when: 1_260 }.into());
+
+		// Sends the renewal
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+		System::assert_last_event(
+			Event::RenewalDispatched { when: 1_260, core: 5, query_id: 1 }.into(),
+		);
+		assert_eq!(DispatchedRenewals::get(), vec![5]);
+		assert_eq!(TransferredFunds::get(), vec![1_100]);
+		assert_eq!(
+			Renewals::<Test>::get(1_260).map(|r| r.status),
+			Some(RenewalStatus::Dispatched(1))
+		);
+		assert_eq!(RenewalQueries::<Test>::get(1), Some(1_260));
+
+		// Failure: The outcome of the renewal is unknown, even once the response is overdue.
+		System::set_block_number(200);
+		assert_noop!(
+			RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260),
+			Error::<Test>::AwaitingResponse
+		);
+	});
+}
+
+#[test]
+fn failed_transfer_returns_the_funds() {
+	new_test_ext(vec![(ALICE, 2_000)]).execute_with(|| {
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 1_000));
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+
+		let error = Response::ExecutionResult(Some((4, XcmError::Unroutable)));
+
+		// Failure: Not reported by the relay chain
+		assert_noop!(
+			RenewalFinancing::notify_renewal_status(RuntimeOrigin::signed(1_005), 0, error.clone()),
+			Error::<Test>::UnexpectedResponder
+		);
+
+		assert_ok!(RenewalFinancing::notify_renewal_status(RuntimeOrigin::signed(RELAY), 0, error));
+		System::assert_last_event(
+			Event::TransferFailed { when: 1_260, error: XcmError::Unroutable }.into(),
+		);
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.status), Some(RenewalStatus::Raising));
+		assert!(RenewalQueries::<Test>::get(0).is_none());
+		assert_eq!(Balances::balance(&RenewalFinancing::account_id()), 1_000);
+
+		// The contributions can be withdrawn again.
+		assert_ok!(RenewalFinancing::withdraw_contribution(
+			RuntimeOrigin::signed(ALICE),
+			1_260,
+			1_000
+		));
+		assert_eq!(Balances::balance(&ALICE), 2_000);
+	});
+}
+
+#[test]
+fn notify_renewal_status_works() {
+	new_test_ext(vec![(ALICE, 2_000)]).execute_with(|| {
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, 1_000));
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, 1_000));
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+		assert_ok!(RenewalFinancing::notify_renewal_status(
+			RuntimeOrigin::signed(RELAY),
+			0,
+			Response::ExecutionResult(None)
+		));
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+
+		let error = Response::ExecutionResult(Some((3, XcmError::FailedToTransactAsset(""))));
+
+		// Failure: Bad origin
+		assert_noop!(
+			RenewalFinancing::notify_renewal_status(RuntimeOrigin::root(), 1, error.clone()),
+			BadOrigin
+		);
+
+		// Failure: Not reported by the Coretime chain
+		assert_noop!(
+			RenewalFinancing::notify_renewal_status(RuntimeOrigin::signed(RELAY), 1, error.clone()),
+			Error::<Test>::UnexpectedResponder
+		);
+
+		// Failure: Unknown query
+		assert_noop!(
+			RenewalFinancing::notify_renewal_status(RuntimeOrigin::signed(1_005), 0, error.clone()),
+			Error::<Test>::UnknownQuery
+		);
+
+		// Failure: Not an execution result
+		assert_noop!(
+			RenewalFinancing::notify_renewal_status(
+				RuntimeOrigin::signed(1_005),
+				1,
+				Response::Null
+			),
+			Error::<Test>::UnexpectedResponse
+		);
+
+		// The renewal failed, e.g. because the price went up.
+		assert_ok!(RenewalFinancing::notify_renewal_status(RuntimeOrigin::signed(1_005), 1, error));
+		System::assert_last_event(
+			Event::RenewalFailed { when: 1_260, error: XcmError::FailedToTransactAsset("") }.into(),
+		);
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.status), Some(RenewalStatus::Failed));
+		assert!(RenewalQueries::<Test>::get(1).is_none());
+
+		// The renewal can be sent again without transferring the funds again.
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+		System::assert_last_event(
+			Event::RenewalDispatched { when: 1_260, core: 5, query_id: 2 }.into(),
+		);
+		assert_eq!(DispatchedRenewals::get(), vec![5, 5]);
+		assert_eq!(TransferredFunds::get(), vec![1_000]);
+
+		// The core got renewed.
+		assert_ok!(RenewalFinancing::notify_renewal_status(
+			RuntimeOrigin::signed(1_005),
+			2,
+			Response::ExecutionResult(None)
+		));
+		System::assert_last_event(Event::Renewed { when: 1_260 }.into());
+		assert_eq!(Renewals::<Test>::get(1_260).map(|r| r.status), Some(RenewalStatus::Renewed));
+
+		// Failure: Already renewed
+		assert_noop!(
+			RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260),
+			Error::<Test>::AlreadyRenewed
+		);
+	});
+}
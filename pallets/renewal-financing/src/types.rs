@@ -0,0 +1,50 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use pallet_broker::CoreIndex;
+use scale_info::{prelude::vec::Vec, TypeInfo};
+use xcm::latest::QueryId;
+
+/// The progress of a renewal.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum RenewalStatus {
+	/// The funds for the renewal are being raised.
+	Raising,
+	/// The raised funds were sent to the relay chain, which will report through the given query
+	/// whether it forwarded them to the Coretime chain.
+	Transferring(QueryId),
+	/// The relay chain forwarded the raised funds to the parachain's sovereign account on the
+	/// Coretime chain.
+	FundsTransferred,
+	/// The renewal was sent to the Coretime chain, which will report its outcome through the
+	/// given query.
+	///
+	/// The renewal isn't sent again while its outcome is unknown, since it could renew the core
+	/// twice.
+	Dispatched(QueryId),
+	/// The Coretime chain reported that the core got renewed.
+	Renewed,
+	/// The Coretime chain reported that the renewal failed.
+	///
+	/// The raised funds are kept in the parachain's sovereign account on the Coretime chain, from
+	/// where the renewal can be paid for once it is sent again.
+	Failed,
+}
+
+/// The state of the crowdfunding for a single bulk renewal.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RenewalRecord<Balance> {
+	/// The core on the Coretime chain which is getting renewed.
+	pub core: CoreIndex,
+	/// The amount that needs to be raised before the renewal can be dispatched.
+	///
+	/// This should cover the renewal price on the Coretime chain as well as the execution fees.
+	pub target: Balance,
+	/// The amount contributed so far.
+	pub raised: Balance,
+	/// The progress of the renewal.
+	pub status: RenewalStatus,
+}
+
+pub trait CallEncoder {
+	/// Returns the runtime call which will renew the given core on the Coretime chain.
+	fn renewal_call(core: CoreIndex) -> Vec<u8>;
+}
@@ -0,0 +1,196 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{
+	assert_ok,
+	traits::fungible::{Inspect, Mutate},
+};
+use sp_runtime::AccountId32;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::ConvertLocation;
+
+use crate::{
+	xcm_mock::{
+		coretime, parachain, relay, reset, take_outcomes, Chain, CoretimePara, Relay,
+		RenewalFinancingPara, CORETIME_PARA_ID, PARA_ID,
+	},
+	RenewalStatus,
+};
+
+const ALICE: AccountId32 = AccountId32::new([1u8; 32]);
+
+/// The amount raised for the renewal.
+const FUNDS: u128 = 2_000_000_000;
+
+/// The sovereign account of our parachain on the Coretime chain, which pays for the renewal.
+fn sovereign_account() -> coretime::AccountId {
+	coretime::LocationToAccountId::convert_location(&MultiLocation::new(1, X1(Parachain(PARA_ID))))
+		.unwrap()
+}
+
+/// The amount paid for executing a message of `instructions` instructions on any chain of the
+/// network.
+///
+/// All chains charge one unit per unit of `ref_time` and per byte of `proof_size`.
+fn execution_fee(instructions: u64, extra: Weight) -> u128 {
+	let weight = parachain::UnitWeightCost::get()
+		.saturating_mul(instructions)
+		.saturating_add(extra);
+	(weight.ref_time() + weight.proof_size()).into()
+}
+
+/// The fees paid for transferring the funds to the Coretime chain.
+///
+/// The relay chain executes eight instructions, including the ones of the appendix, while the
+/// Coretime chain executes the four instructions depositing the funds.
+fn transfer_fees() -> u128 {
+	execution_fee(8, Weight::zero()) + execution_fee(4, Weight::zero())
+}
+
+/// The fee paid for executing the nine instructions of the renewal on the Coretime chain.
+fn renewal_fee() -> u128 {
+	execution_fee(9, parachain::RenewalCallWeight::get())
+}
+
+/// Returns the progress of the renewal.
+fn renewal_status() -> RenewalStatus {
+	RenewalFinancingPara::execute_with(|| {
+		parachain::RenewalFinancing::renewals(1_260)
+			.map(|record| record.status)
+			.unwrap()
+	})
+}
+
+/// Sends the renewal, or its funds if they haven't been transferred yet.
+fn renew() {
+	RenewalFinancingPara::execute_with(|| {
+		use parachain::{RenewalFinancing, RuntimeOrigin};
+
+		assert_ok!(RenewalFinancing::renew(RuntimeOrigin::signed(ALICE), 1_260));
+	});
+}
+
+/// Raises `FUNDS` for renewing core 5 and transfers them to the Coretime chain, where a renewal
+/// costs `price`.
+fn raise_and_transfer(price: u128) {
+	reset();
+	CoretimePara::execute_with(|| {
+		coretime::broker::RenewalPrice::<coretime::Runtime>::put(price);
+	});
+
+	RenewalFinancingPara::execute_with(|| {
+		use parachain::{Balances, RenewalFinancing, RuntimeOrigin};
+
+		assert_ok!(Balances::mint_into(&ALICE, 10_000_000_000));
+		assert_ok!(RenewalFinancing::set_renewal_target(RuntimeOrigin::root(), 1_260, 5, FUNDS));
+		assert_ok!(RenewalFinancing::contribute(RuntimeOrigin::signed(ALICE), 1_260, FUNDS));
+	});
+	renew();
+
+	// The relay chain forwards the funds from their reserve to the Coretime chain and reports
+	// back that it did.
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 3);
+	assert!(matches!(outcomes[0], (Chain::Relay, Outcome::Complete(_))));
+	assert!(matches!(outcomes[1], (Chain::Para(CORETIME_PARA_ID), Outcome::Complete(_))));
+	assert!(matches!(outcomes[2], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+	assert_eq!(renewal_status(), RenewalStatus::FundsTransferred);
+
+	// The funds left the parachain.
+	RenewalFinancingPara::execute_with(|| {
+		assert_eq!(parachain::Balances::balance(&parachain::RenewalFinancing::account_id()), 0);
+	});
+}
+
+#[test]
+fn renewal_is_paid_from_the_sovereign_account() {
+	let price = 1_000_000_000;
+	raise_and_transfer(price);
+
+	Relay::execute_with(|| {
+		assert_eq!(
+			relay::Balances::balance(&relay::sovereign_account(PARA_ID)),
+			relay::PARACHAIN_RESERVE - FUNDS
+		);
+		assert_eq!(
+			relay::Balances::balance(&relay::sovereign_account(CORETIME_PARA_ID)),
+			FUNDS - execution_fee(8, Weight::zero())
+		);
+	});
+
+	// The renewal is only sent once the funds are on the Coretime chain.
+	renew();
+
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 2);
+	assert!(matches!(outcomes[0], (Chain::Para(CORETIME_PARA_ID), Outcome::Complete(_))));
+	assert!(matches!(outcomes[1], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+	assert_eq!(renewal_status(), RenewalStatus::Renewed);
+
+	CoretimePara::execute_with(|| {
+		assert_eq!(
+			coretime::broker::Renewals::<coretime::Runtime>::get(),
+			vec![(sovereign_account(), 5)]
+		);
+		assert_eq!(
+			coretime::Balances::balance(&sovereign_account()),
+			FUNDS - transfer_fees() - renewal_fee() - price
+		);
+	});
+}
+
+#[test]
+fn failed_renewal_can_be_sent_again() {
+	// The renewal costs more than what was raised.
+	raise_and_transfer(FUNDS);
+	renew();
+
+	let outcomes = take_outcomes();
+	assert_eq!(outcomes.len(), 2);
+	assert!(matches!(
+		outcomes[0],
+		(Chain::Para(CORETIME_PARA_ID), Outcome::Incomplete(_, XcmError::ExpectationFalse))
+	));
+	assert!(matches!(outcomes[1], (Chain::Para(PARA_ID), Outcome::Complete(_))));
+	assert_eq!(renewal_status(), RenewalStatus::Failed);
+
+	CoretimePara::execute_with(|| {
+		assert!(coretime::broker::Renewals::<coretime::Runtime>::get().is_empty());
+		// Only the fees of the messages were paid.
+		assert_eq!(
+			coretime::Balances::balance(&sovereign_account()),
+			FUNDS - transfer_fees() - renewal_fee()
+		);
+	});
+
+	// Once the renewal gets cheaper, it is paid from the funds kept in the sovereign account.
+	let price = 1_000_000_000;
+	CoretimePara::execute_with(|| {
+		coretime::broker::RenewalPrice::<coretime::Runtime>::put(price);
+	});
+	renew();
+	assert_eq!(renewal_status(), RenewalStatus::Renewed);
+
+	CoretimePara::execute_with(|| {
+		assert_eq!(
+			coretime::broker::Renewals::<coretime::Runtime>::get(),
+			vec![(sovereign_account(), 5)]
+		);
+		assert_eq!(
+			coretime::Balances::balance(&sovereign_account()),
+			FUNDS - transfer_fees() - 2 * renewal_fee() - price
+		);
+	});
+}
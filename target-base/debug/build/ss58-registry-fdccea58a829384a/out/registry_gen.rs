@@ -0,0 +1 @@
+# [doc = r" A known address (sub)format/network ID for SS58."] # [non_exhaustive] # [repr (u16)] # [derive (Copy , Clone , PartialEq , Eq , Debug)] pub enum Ss58AddressFormatRegistry { # [doc = "Bare 32-bit Ed25519 public key."] BareEd25519Account = 3u16 , # [doc = "Bare 32-bit ECDSA SECP-256k1 public key."] BareSecp256K1Account = 43u16 , # [doc = "Bare 32-bit Schnorr/Ristretto (S/R 25519) public key."] BareSr25519Account = 1u16 , # [doc = "DICO - <https://dico.io>"] DicoAccount = 53u16 , # [doc = "ICE Network - <https://icenetwork.io>"] IceAccount = 2206u16 , # [doc = "KICO - <https://dico.io>"] KicoAccount = 52u16 , # [doc = "SNOW: ICE Canary Network - <https://icenetwork.io>"] SnowAccount = 2207u16 , # [doc = "Acala - <https://acala.network/>"] AcalaAccount = 10u16 , # [doc = "Ajuna Network - <https://ajuna.io>"] AjunaAccount = 1328u16 , # [doc = "Allfeat Network - <https://allfeat.network>"] AllfeatNetworkAccount = 440u16 , # [doc = "Altair - <https://centrifuge.io/>"] AltairAccount = 136u16 , # [doc = "Amplitude chain - <https://pendulumchain.org/>"] AmplitudeAccount = 57u16 , # [doc = "Anmol Network - <https://anmol.network/>"] AnmolAccount = 92u16 , # [doc = "Ares Protocol - <https://www.aresprotocol.com/>"] AresAccount = 34u16 , # [doc = "Astar Network - <https://astar.network>"] AstarAccount = 5u16 , # [doc = "Aventus Mainnet - <https://aventus.io>"] AventusAccount = 65u16 , # [doc = "Bajun Network - <https://ajuna.io>"] BajunAccount = 1337u16 , # [doc = "Basilisk - <https://bsx.fi>"] BasiliskAccount = 10041u16 , # [doc = "Bifrost - <https://bifrost.finance/>"] BifrostAccount = 6u16 , # [doc = "Bitgreen - <https://bitgreen.org/>"] BitgreenAccount = 2106u16 , # [doc = "Bittensor - <https://bittensor.com>"] BittensorAccount = 13116u16 , # [doc = "Calamari: Manta Canary Network - <https://manta.network>"] CalamariAccount = 78u16 , # [doc = "Centrifuge Chain - <https://centrifuge.io/>"] CentrifugeAccount = 36u16 , # [doc = "Cere Network - <https://cere.network>"] CereAccount = 54u16 , # [doc = "CESS - <https://cess.cloud>"] CessAccount = 11331u16 , # [doc = "CESS Testnet - <https://cess.cloud>"] CessTestnetAccount = 11330u16 , # [doc = "Chainflip - <https://chainflip.io/>"] ChainflipAccount = 2112u16 , # [doc = "ChainX - <https://chainx.org/>"] ChainxAccount = 44u16 , # [doc = "CloudWalk Network Mainnet - <https://explorer.mainnet.cloudwalk.io>"] CloudwalkMainnetAccount = 2009u16 , # [doc = "Clover Finance - <https://clover.finance>"] CloverAccount = 128u16 , # [doc = "Composable Finance - <https://composable.finance>"] ComposableAccount = 50u16 , # [doc = "Automata ContextFree - <https://ata.network>"] ContextfreeAccount = 11820u16 , # [doc = "CORD Network - <https://cord.network/>"] CordAccount = 29u16 , # [doc = "Crust Network - <https://crust.network>"] CrustAccount = 66u16 , # [doc = "Curio - <https://parachain.capitaldex.exchange/>"] CurioAccount = 777u16 , # [doc = "Dark Mainnet"] DarkAccount = 17u16 , # [doc = "Darwinia Network - <https://darwinia.network>"] DarwiniaAccount = 18u16 , # [doc = "DataHighway"] DatahighwayAccount = 33u16 , # [doc = "DENTNet - <https://www.dentnet.io>"] DentnetAccount = 9807u16 , # [doc = "Dock Mainnet - <https://dock.io>"] DockPosMainnetAccount = 22u16 , # [doc = "Dorafactory Polkadot Network - <https://dorafactory.org>"] DorafactoryPolkadotAccount = 129u16 , # [doc = "Edgeware - <https://edgewa.re>"] EdgewareAccount = 7u16 , # [doc = "Efinity - <https://efinity.io/>"] EfinityAccount = 1110u16 , # [doc = "Equilibrium Network - <https://equilibrium.io>"] EquilibriumAccount = 68u16 , # [doc = "Eternal Civilization - <http://www.ysknfr.cn/>"] EternalCivilizationAccount = 58u16 , # [doc = "Fragnova Network - <https://fragnova.com>"] FragnovaAccount = 93u16 , # [doc = "Frequency - <https://www.frequency.xyz>"] FrequencyAccount = 90u16 , # [doc = "Ğ1 - <https://duniter.org>"] G1Account = 4450u16 , # [doc = "GEEK Network - <https://geek.gl>"] GeekAccount = 789u16 , # [doc = "Genshiro Network - <https://genshiro.equilibrium.io>"] GenshiroAccount = 67u16 , # [doc = "GM - <https://gmordie.com>"] GmAccount = 7013u16 , # [doc = "Golden Gate - <https://ggxchain.io/>"] GoldenGateAccount = 8866u16 , # [doc = "Golden Gate Sydney - <https://ggxchain.io/>"] GoldenGateSydneyAccount = 8886u16 , # [doc = "GORO Network - <https://goro.network>"] GoroAccount = 14697u16 , # [doc = "Hashed Network - <https://hashed.network>"] HashedAccount = 9072u16 , # [doc = "Heiko - <https://parallel.fi/>"] HeikoAccount = 110u16 , # [doc = "Humanode Network - <https://humanode.io>"] HumanodeAccount = 5234u16 , # [doc = "HydraDX - <https://hydradx.io>"] HydradxAccount = 63u16 , # [doc = "Anmol Network Ibtida Canary network - <https://anmol.network/>"] IbtidaAccount = 100u16 , # [doc = "Impact Protocol Network - <https://impactprotocol.network/>"] ImpactAccount = 12155u16 , # [doc = "Integritee - <https://integritee.network>"] IntegriteeAccount = 13u16 , # [doc = "Integritee Incognito - <https://integritee.network>"] IntegriteeIncognitoAccount = 113u16 , # [doc = "Interlay - <https://interlay.io/>"] InterlayAccount = 2032u16 , # [doc = "Joystream - <https://www.joystream.org>"] JoystreamAccount = 126u16 , # [doc = "Jupiter - <https://jupiter.patract.io>"] JupiterAccount = 26u16 , # [doc = "Kabocha - <https://kabocha.network>"] KabochaAccount = 27u16 , # [doc = "Kapex - <https://totemaccounting.com>"] KapexAccount = 2007u16 , # [doc = "Karmacoin - <https://karmaco.in>"] KarmachainAccount = 21u16 , # [doc = "Karura - <https://karura.network/>"] KaruraAccount = 8u16 , # [doc = "Katal Chain"] KatalchainAccount = 4u16 , # [doc = "KILT Spiritnet - <https://kilt.io/>"] KiltAccount = 38u16 , # [doc = "Kintsugi - <https://interlay.io/>"] KintsugiAccount = 2092u16 , # [doc = "Krest Network - <https://www.peaq.network/>"] KrestAccount = 1222u16 , # [doc = "Krigan Network - <https://krigan.network>"] KriganAccount = 7306u16 , # [doc = "Kulupu - <https://kulupu.network/>"] KulupuAccount = 16u16 , # [doc = "Kusama Relay Chain - <https://kusama.network>"] KusamaAccount = 2u16 , # [doc = "Laminar - <http://laminar.network/>"] LaminarAccount = 11u16 , # [doc = "Litentry Network - <https://litentry.com/>"] LitentryAccount = 31u16 , # [doc = "Litmus Network - <https://litentry.com/>"] LitmusAccount = 131u16 , # [doc = "logion network - <https://logion.network>"] LogionAccount = 2021u16 , # [doc = "Luhn Network - <https://luhn.network>"] LuhnAccount = 11486u16 , # [doc = "Manta network - <https://manta.network>"] MantaAccount = 77u16 , # [doc = "MathChain mainnet - <https://mathwallet.org>"] MathchainAccount = 39u16 , # [doc = "MathChain testnet - <https://mathwallet.org>"] MathchainTestnetAccount = 40u16 , # [doc = "Metaquity Network - <https://metaquity.xyz/>"] MetaquityNetworkAccount = 666u16 , # [doc = "Moonbeam - <https://moonbeam.network>"] MoonbeamAccount = 1284u16 , # [doc = "Moonriver - <https://moonbeam.network>"] MoonriverAccount = 1285u16 , # [doc = "Moonsama - <https://moonsama.com>"] MoonsamaAccount = 2199u16 , # [doc = "Mythos - <https://mythos.foundation>"] MythosAccount = 29972u16 , # [doc = "Neatcoin Mainnet - <https://neatcoin.org>"] NeatcoinAccount = 48u16 , # [doc = "NFTMart - <https://nftmart.io>"] NftmartAccount = 12191u16 , # [doc = "Nodle Chain - <https://nodle.io/>"] NodleAccount = 37u16 , # [doc = "OAK Network - <https://oak.tech>"] OakAccount = 51u16 , # [doc = "OriginTrail Parachain - <https://parachain.origintrail.io/>"] OrigintrailParachainAccount = 101u16 , # [doc = "3DP network - <https://3dpass.org>"] P3DAccount = 71u16 , # [doc = "3DP test network - <https://3dpass.org>"] P3DtAccount = 72u16 , # [doc = "Parallel - <https://parallel.fi/>"] ParallelAccount = 172u16 , # [doc = "Peaq Network - <https://www.peaq.network/>"] PeaqAccount = 1221u16 , # [doc = "Peerplays - <https://www.peerplays.com/>"] PeerplaysAccount = 3333u16 , # [doc = "Pendulum chain - <https://pendulumchain.org/>"] PendulumAccount = 56u16 , # [doc = "Phala Network - <https://phala.network>"] PhalaAccount = 30u16 , # [doc = "Picasso - <https://picasso.composable.finance>"] PicassoAccount = 49u16 , # [doc = "Pioneer Network by Bit.Country - <https://bit.country>"] PioneerNetworkAccount = 268u16 , # [doc = "Polimec Protocol - <https://www.polimec.org/>"] PolimecAccount = 41u16 , # [doc = "Polkadex Mainnet - <https://polkadex.trade>"] PolkadexAccount = 88u16 , # [doc = "Polkadex Parachain - <https://polkadex.trade>"] PolkadexparachainAccount = 89u16 , # [doc = "Polkadot Relay Chain - <https://polkadot.network>"] PolkadotAccount = 0u16 , # [doc = "PolkaFoundry Network - <https://polkafoundry.com>"] PolkafoundryAccount = 99u16 , # [doc = "PolkaSmith Canary Network - <https://polkafoundry.com>"] PolkasmithAccount = 98u16 , # [doc = "Polymesh - <https://polymath.network/>"] PolymeshAccount = 12u16 , # [doc = "Pontem Network - <https://pontem.network>"] PontemNetworkAccount = 105u16 , # [doc = "QUARTZ by UNIQUE - <https://unique.network>"] QuartzMainnetAccount = 255u16 , # [doc = "This prefix is reserved."] Reserved46Account = 46u16 , # [doc = "This prefix is reserved."] Reserved47Account = 47u16 , # [doc = "Laminar Reynolds Canary - <http://laminar.network/>"] ReynoldsAccount = 9u16 , # [doc = "Robonomics - <https://robonomics.network>"] RobonomicsAccount = 32u16 , # [doc = "Sapphire by Unique - <https://unique.network>"] SapphireMainnetAccount = 8883u16 , # [doc = "Seals Network - <https://seals.app>"] SealsAccount = 1985u16 , # [doc = "ShiftNrg"] ShiftAccount = 23u16 , # [doc = "Social Network - <https://social.network>"] SocialNetworkAccount = 252u16 , # [doc = "Societal - <https://www.sctl.xyz>"] SocietalAccount = 1516u16 , # [doc = "SORA Network - <https://sora.org>"] SoraAccount = 69u16 , # [doc = "SORA Polkadot Parachain - <https://sora.org>"] SoraDotParaAccount = 81u16 , # [doc = "SORA Kusama Parachain - <https://sora.org>"] SoraKusamaParaAccount = 420u16 , # [doc = "Stafi - <https://stafi.io>"] StafiAccount = 20u16 , # [doc = "Subsocial"] SubsocialAccount = 28u16 , # [doc = "Subspace - <https://subspace.network>"] SubspaceAccount = 6094u16 , # [doc = "Subspace testnet - <https://subspace.network>"] SubspaceTestnetAccount = 2254u16 , # [doc = "Substrate - <https://substrate.io/>"] SubstrateAccount = 42u16 , # [doc = "Synesthesia - <https://synesthesia.network/>"] SynesthesiaAccount = 15u16 , # [doc = "t3rn - <https://t3rn.io/>"] T3RnAccount = 9935u16 , # [doc = "Tangle Network - <https://www.tangle.tools/>"] TangleAccount = 5845u16 , # [doc = "Ternoa - <https://www.ternoa.network>"] TernoaAccount = 995u16 , # [doc = "Tidefi - <https://tidefi.com>"] TidefiAccount = 7007u16 , # [doc = "Tinker - <https://invarch.network>"] TinkerAccount = 117u16 , # [doc = "Totem - <https://totemaccounting.com>"] TotemAccount = 14u16 , # [doc = "UniArts Network - <https://uniarts.me>"] UniartsAccount = 45u16 , # [doc = "Unique Network - <https://unique.network>"] UniqueMainnetAccount = 7391u16 , # [doc = "Vara Network - <https://vara.network/>"] VaraAccount = 137u16 , # [doc = "Valiu Liquidity Network - <https://valiu.com/>"] VlnAccount = 35u16 , # [doc = "Watr Protocol - <https://www.watr.org>"] WatrAccount = 19u16 , # [doc = "xx network - <https://xx.network>"] XxnetworkAccount = 55u16 , # [doc = "Zeitgeist - <https://zeitgeist.pm>"] ZeitgeistAccount = 73u16 , # [doc = "ZERO - <https://zero.io>"] ZeroAccount = 24u16 , # [doc = "ZERO Alphaville - <https://zero.io>"] ZeroAlphavilleAccount = 25u16 , } # [doc = r" All non-custom address formats (Sorted by network)"] pub (crate) static ALL_SS58_ADDRESS_FORMATS : [Ss58AddressFormatRegistry ; 145usize] = [Ss58AddressFormatRegistry :: BareEd25519Account , Ss58AddressFormatRegistry :: BareSecp256K1Account , Ss58AddressFormatRegistry :: BareSr25519Account , Ss58AddressFormatRegistry :: DicoAccount , Ss58AddressFormatRegistry :: IceAccount , Ss58AddressFormatRegistry :: KicoAccount , Ss58AddressFormatRegistry :: SnowAccount , Ss58AddressFormatRegistry :: AcalaAccount , Ss58AddressFormatRegistry :: AjunaAccount , Ss58AddressFormatRegistry :: AllfeatNetworkAccount , Ss58AddressFormatRegistry :: AltairAccount , Ss58AddressFormatRegistry :: AmplitudeAccount , Ss58AddressFormatRegistry :: AnmolAccount , Ss58AddressFormatRegistry :: AresAccount , Ss58AddressFormatRegistry :: AstarAccount , Ss58AddressFormatRegistry :: AventusAccount , Ss58AddressFormatRegistry :: BajunAccount , Ss58AddressFormatRegistry :: BasiliskAccount , Ss58AddressFormatRegistry :: BifrostAccount , Ss58AddressFormatRegistry :: BitgreenAccount , Ss58AddressFormatRegistry :: BittensorAccount , Ss58AddressFormatRegistry :: CalamariAccount , Ss58AddressFormatRegistry :: CentrifugeAccount , Ss58AddressFormatRegistry :: CereAccount , Ss58AddressFormatRegistry :: CessAccount , Ss58AddressFormatRegistry :: CessTestnetAccount , Ss58AddressFormatRegistry :: ChainflipAccount , Ss58AddressFormatRegistry :: ChainxAccount , Ss58AddressFormatRegistry :: CloudwalkMainnetAccount , Ss58AddressFormatRegistry :: CloverAccount , Ss58AddressFormatRegistry :: ComposableAccount , Ss58AddressFormatRegistry :: ContextfreeAccount , Ss58AddressFormatRegistry :: CordAccount , Ss58AddressFormatRegistry :: CrustAccount , Ss58AddressFormatRegistry :: CurioAccount , Ss58AddressFormatRegistry :: DarkAccount , Ss58AddressFormatRegistry :: DarwiniaAccount , Ss58AddressFormatRegistry :: DatahighwayAccount , Ss58AddressFormatRegistry :: DentnetAccount , Ss58AddressFormatRegistry :: DockPosMainnetAccount , Ss58AddressFormatRegistry :: DorafactoryPolkadotAccount , Ss58AddressFormatRegistry :: EdgewareAccount , Ss58AddressFormatRegistry :: EfinityAccount , Ss58AddressFormatRegistry :: EquilibriumAccount , Ss58AddressFormatRegistry :: EternalCivilizationAccount , Ss58AddressFormatRegistry :: FragnovaAccount , Ss58AddressFormatRegistry :: FrequencyAccount , Ss58AddressFormatRegistry :: G1Account , Ss58AddressFormatRegistry :: GeekAccount , Ss58AddressFormatRegistry :: GenshiroAccount , Ss58AddressFormatRegistry :: GmAccount , Ss58AddressFormatRegistry :: GoldenGateAccount , Ss58AddressFormatRegistry :: GoldenGateSydneyAccount , Ss58AddressFormatRegistry :: GoroAccount , Ss58AddressFormatRegistry :: HashedAccount , Ss58AddressFormatRegistry :: HeikoAccount , Ss58AddressFormatRegistry :: HumanodeAccount , Ss58AddressFormatRegistry :: HydradxAccount , Ss58AddressFormatRegistry :: IbtidaAccount , Ss58AddressFormatRegistry :: ImpactAccount , Ss58AddressFormatRegistry :: IntegriteeAccount , Ss58AddressFormatRegistry :: IntegriteeIncognitoAccount , Ss58AddressFormatRegistry :: InterlayAccount , Ss58AddressFormatRegistry :: JoystreamAccount , Ss58AddressFormatRegistry :: JupiterAccount , Ss58AddressFormatRegistry :: KabochaAccount , Ss58AddressFormatRegistry :: KapexAccount , Ss58AddressFormatRegistry :: KarmachainAccount , Ss58AddressFormatRegistry :: KaruraAccount , Ss58AddressFormatRegistry :: KatalchainAccount , Ss58AddressFormatRegistry :: KiltAccount , Ss58AddressFormatRegistry :: KintsugiAccount , Ss58AddressFormatRegistry :: KrestAccount , Ss58AddressFormatRegistry :: KriganAccount , Ss58AddressFormatRegistry :: KulupuAccount , Ss58AddressFormatRegistry :: KusamaAccount , Ss58AddressFormatRegistry :: LaminarAccount , Ss58AddressFormatRegistry :: LitentryAccount , Ss58AddressFormatRegistry :: LitmusAccount , Ss58AddressFormatRegistry :: LogionAccount , Ss58AddressFormatRegistry :: LuhnAccount , Ss58AddressFormatRegistry :: MantaAccount , Ss58AddressFormatRegistry :: MathchainAccount , Ss58AddressFormatRegistry :: MathchainTestnetAccount , Ss58AddressFormatRegistry :: MetaquityNetworkAccount , Ss58AddressFormatRegistry :: MoonbeamAccount , Ss58AddressFormatRegistry :: MoonriverAccount , Ss58AddressFormatRegistry :: MoonsamaAccount , Ss58AddressFormatRegistry :: MythosAccount , Ss58AddressFormatRegistry :: NeatcoinAccount , Ss58AddressFormatRegistry :: NftmartAccount , Ss58AddressFormatRegistry :: NodleAccount , Ss58AddressFormatRegistry :: OakAccount , Ss58AddressFormatRegistry :: OrigintrailParachainAccount , Ss58AddressFormatRegistry :: P3DAccount , Ss58AddressFormatRegistry :: P3DtAccount , Ss58AddressFormatRegistry :: ParallelAccount , Ss58AddressFormatRegistry :: PeaqAccount , Ss58AddressFormatRegistry :: PeerplaysAccount , Ss58AddressFormatRegistry :: PendulumAccount , Ss58AddressFormatRegistry :: PhalaAccount , Ss58AddressFormatRegistry :: PicassoAccount , Ss58AddressFormatRegistry :: PioneerNetworkAccount , Ss58AddressFormatRegistry :: PolimecAccount , Ss58AddressFormatRegistry :: PolkadexAccount , Ss58AddressFormatRegistry :: PolkadexparachainAccount , Ss58AddressFormatRegistry :: PolkadotAccount , Ss58AddressFormatRegistry :: PolkafoundryAccount , Ss58AddressFormatRegistry :: PolkasmithAccount , Ss58AddressFormatRegistry :: PolymeshAccount , Ss58AddressFormatRegistry :: PontemNetworkAccount , Ss58AddressFormatRegistry :: QuartzMainnetAccount , Ss58AddressFormatRegistry :: Reserved46Account , Ss58AddressFormatRegistry :: Reserved47Account , Ss58AddressFormatRegistry :: ReynoldsAccount , Ss58AddressFormatRegistry :: RobonomicsAccount , Ss58AddressFormatRegistry :: SapphireMainnetAccount , Ss58AddressFormatRegistry :: SealsAccount , Ss58AddressFormatRegistry :: ShiftAccount , Ss58AddressFormatRegistry :: SocialNetworkAccount , Ss58AddressFormatRegistry :: SocietalAccount , Ss58AddressFormatRegistry :: SoraAccount , Ss58AddressFormatRegistry :: SoraDotParaAccount , Ss58AddressFormatRegistry :: SoraKusamaParaAccount , Ss58AddressFormatRegistry :: StafiAccount , Ss58AddressFormatRegistry :: SubsocialAccount , Ss58AddressFormatRegistry :: SubspaceAccount , Ss58AddressFormatRegistry :: SubspaceTestnetAccount , Ss58AddressFormatRegistry :: SubstrateAccount , Ss58AddressFormatRegistry :: SynesthesiaAccount , Ss58AddressFormatRegistry :: T3RnAccount , Ss58AddressFormatRegistry :: TangleAccount , Ss58AddressFormatRegistry :: TernoaAccount , Ss58AddressFormatRegistry :: TidefiAccount , Ss58AddressFormatRegistry :: TinkerAccount , Ss58AddressFormatRegistry :: TotemAccount , Ss58AddressFormatRegistry :: UniartsAccount , Ss58AddressFormatRegistry :: UniqueMainnetAccount , Ss58AddressFormatRegistry :: VaraAccount , Ss58AddressFormatRegistry :: VlnAccount , Ss58AddressFormatRegistry :: WatrAccount , Ss58AddressFormatRegistry :: XxnetworkAccount , Ss58AddressFormatRegistry :: ZeitgeistAccount , Ss58AddressFormatRegistry :: ZeroAccount , Ss58AddressFormatRegistry :: ZeroAlphavilleAccount ,] ; # [doc = r" Names of all address formats (Sorted by network)"] pub (crate) static ALL_SS58_ADDRESS_FORMAT_NAMES : [& str ; 145usize] = ["BareEd25519" , "BareSecp256k1" , "BareSr25519" , "DICO" , "ICE" , "KICO" , "SNOW" , "acala" , "ajuna" , "allfeat_network" , "altair" , "amplitude" , "anmol" , "ares" , "astar" , "aventus" , "bajun" , "basilisk" , "bifrost" , "bitgreen" , "bittensor" , "calamari" , "centrifuge" , "cere" , "cess" , "cess-testnet" , "chainflip" , "chainx" , "cloudwalk_mainnet" , "clover" , "composable" , "contextfree" , "cord" , "crust" , "curio" , "dark" , "darwinia" , "datahighway" , "dentnet" , "dock-pos-mainnet" , "dorafactory-polkadot" , "edgeware" , "efinity" , "equilibrium" , "eternal-civilization" , "fragnova" , "frequency" , "g1" , "geek" , "genshiro" , "gm" , "golden_gate" , "golden_gate_sydney" , "goro" , "hashed" , "heiko" , "humanode" , "hydradx" , "ibtida" , "impact" , "integritee" , "integritee-incognito" , "interlay" , "joystream" , "jupiter" , "kabocha" , "kapex" , "karmachain" , "karura" , "katalchain" , "kilt" , "kintsugi" , "krest" , "krigan" , "kulupu" , "kusama" , "laminar" , "litentry" , "litmus" , "logion" , "luhn" , "manta" , "mathchain" , "mathchain-testnet" , "metaquity_network" , "moonbeam" , "moonriver" , "moonsama" , "mythos" , "neatcoin" , "nftmart" , "nodle" , "oak" , "origintrail-parachain" , "p3d" , "p3dt" , "parallel" , "peaq" , "peerplays" , "pendulum" , "phala" , "picasso" , "pioneer_network" , "polimec" , "polkadex" , "polkadexparachain" , "polkadot" , "polkafoundry" , "polkasmith" , "polymesh" , "pontem-network" , "quartz_mainnet" , "reserved46" , "reserved47" , "reynolds" , "robonomics" , "sapphire_mainnet" , "seals" , "shift" , "social-network" , "societal" , "sora" , "sora_dot_para" , "sora_kusama_para" , "stafi" , "subsocial" , "subspace" , "subspace_testnet" , "substrate" , "synesthesia" , "t3rn" , "tangle" , "ternoa" , "tidefi" , "tinker" , "totem" , "uniarts" , "unique_mainnet" , "vara" , "vln" , "watr" , "xxnetwork" , "zeitgeist" , "zero" , "zero-alphaville" ,] ; # [doc = r" (Sorted) prefixes to index of ALL_SS58_ADDRESS_FORMATS"] pub (crate) static PREFIX_TO_INDEX : [(u16 , usize) ; 145usize] = [(0u16 , 106usize) , (1u16 , 2usize) , (2u16 , 75usize) , (3u16 , 0usize) , (4u16 , 69usize) , (5u16 , 14usize) , (6u16 , 18usize) , (7u16 , 41usize) , (8u16 , 68usize) , (9u16 , 114usize) , (10u16 , 7usize) , (11u16 , 76usize) , (12u16 , 109usize) , (13u16 , 60usize) , (14u16 , 135usize) , (15u16 , 129usize) , (16u16 , 74usize) , (17u16 , 35usize) , (18u16 , 36usize) , (19u16 , 140usize) , (20u16 , 124usize) , (21u16 , 67usize) , (22u16 , 39usize) , (23u16 , 118usize) , (24u16 , 143usize) , (25u16 , 144usize) , (26u16 , 64usize) , (27u16 , 65usize) , (28u16 , 125usize) , (29u16 , 32usize) , (30u16 , 100usize) , (31u16 , 77usize) , (32u16 , 115usize) , (33u16 , 37usize) , (34u16 , 13usize) , (35u16 , 139usize) , (36u16 , 22usize) , (37u16 , 91usize) , (38u16 , 70usize) , (39u16 , 82usize) , (40u16 , 83usize) , (41u16 , 103usize) , (42u16 , 128usize) , (43u16 , 1usize) , (44u16 , 27usize) , (45u16 , 136usize) , (46u16 , 112usize) , (47u16 , 113usize) , (48u16 , 89usize) , (49u16 , 101usize) , (50u16 , 30usize) , (51u16 , 92usize) , (52u16 , 5usize) , (53u16 , 3usize) , (54u16 , 23usize) , (55u16 , 141usize) , (56u16 , 99usize) , (57u16 , 11usize) , (58u16 , 44usize) , (63u16 , 57usize) , (65u16 , 15usize) , (66u16 , 33usize) , (67u16 , 49usize) , (68u16 , 43usize) , (69u16 , 121usize) , (71u16 , 94usize) , (72u16 , 95usize) , (73u16 , 142usize) , (77u16 , 81usize) , (78u16 , 21usize) , (81u16 , 122usize) , (88u16 , 104usize) , (89u16 , 105usize) , (90u16 , 46usize) , (92u16 , 12usize) , (93u16 , 45usize) , (98u16 , 108usize) , (99u16 , 107usize) , (100u16 , 58usize) , (101u16 , 93usize) , (105u16 , 110usize) , (110u16 , 55usize) , (113u16 , 61usize) , (117u16 , 134usize) , (126u16 , 63usize) , (128u16 , 29usize) , (129u16 , 40usize) , (131u16 , 78usize) , (136u16 , 10usize) , (137u16 , 138usize) , (172u16 , 96usize) , (252u16 , 119usize) , (255u16 , 111usize) , (268u16 , 102usize) , (420u16 , 123usize) , (440u16 , 9usize) , (666u16 , 84usize) , (777u16 , 34usize) , (789u16 , 48usize) , (995u16 , 132usize) , (1110u16 , 42usize) , (1221u16 , 97usize) , (1222u16 , 72usize) , (1284u16 , 85usize) , (1285u16 , 86usize) , (1328u16 , 8usize) , (1337u16 , 16usize) , (1516u16 , 120usize) , (1985u16 , 117usize) , (2007u16 , 66usize) , (2009u16 , 28usize) , (2021u16 , 79usize) , (2032u16 , 62usize) , (2092u16 , 71usize) , (2106u16 , 19usize) , (2112u16 , 26usize) , (2199u16 , 87usize) , (2206u16 , 4usize) , (2207u16 , 6usize) , (2254u16 , 127usize) , (3333u16 , 98usize) , (4450u16 , 47usize) , (5234u16 , 56usize) , (5845u16 , 131usize) , (6094u16 , 126usize) , (7007u16 , 133usize) , (7013u16 , 50usize) , (7306u16 , 73usize) , (7391u16 , 137usize) , (8866u16 , 51usize) , (8883u16 , 116usize) , (8886u16 , 52usize) , (9072u16 , 54usize) , (9807u16 , 38usize) , (9935u16 , 130usize) , (10041u16 , 17usize) , (11330u16 , 25usize) , (11331u16 , 24usize) , (11486u16 , 80usize) , (11820u16 , 31usize) , (12155u16 , 59usize) , (12191u16 , 90usize) , (13116u16 , 20usize) , (14697u16 , 53usize) , (29972u16 , 88usize) ,] ; impl Ss58AddressFormatRegistry { # [doc = r" Tokens used on the given network."] pub fn tokens (& self) -> & 'static [TokenRegistry] { match self { Ss58AddressFormatRegistry :: BareEd25519Account => & [] , Ss58AddressFormatRegistry :: BareSecp256K1Account => & [] , Ss58AddressFormatRegistry :: BareSr25519Account => & [] , Ss58AddressFormatRegistry :: DicoAccount => & [TokenRegistry :: Dico ,] , Ss58AddressFormatRegistry :: IceAccount => & [TokenRegistry :: Icy ,] , Ss58AddressFormatRegistry :: KicoAccount => & [TokenRegistry :: Kico ,] , Ss58AddressFormatRegistry :: SnowAccount => & [TokenRegistry :: Icz ,] , Ss58AddressFormatRegistry :: AcalaAccount => & [TokenRegistry :: Aca ,] , Ss58AddressFormatRegistry :: AjunaAccount => & [TokenRegistry :: Ajun ,] , Ss58AddressFormatRegistry :: AllfeatNetworkAccount => & [TokenRegistry :: Aft ,] , Ss58AddressFormatRegistry :: AltairAccount => & [TokenRegistry :: Air ,] , Ss58AddressFormatRegistry :: AmplitudeAccount => & [TokenRegistry :: Ampe ,] , Ss58AddressFormatRegistry :: AnmolAccount => & [TokenRegistry :: Anml ,] , Ss58AddressFormatRegistry :: AresAccount => & [TokenRegistry :: Ares ,] , Ss58AddressFormatRegistry :: AstarAccount => & [TokenRegistry :: Astr ,] , Ss58AddressFormatRegistry :: AventusAccount => & [TokenRegistry :: Avt ,] , Ss58AddressFormatRegistry :: BajunAccount => & [TokenRegistry :: Baju ,] , Ss58AddressFormatRegistry :: BasiliskAccount => & [TokenRegistry :: Bsx ,] , Ss58AddressFormatRegistry :: BifrostAccount => & [TokenRegistry :: Bnc ,] , Ss58AddressFormatRegistry :: BitgreenAccount => & [TokenRegistry :: Bbb ,] , Ss58AddressFormatRegistry :: BittensorAccount => & [TokenRegistry :: Tao ,] , Ss58AddressFormatRegistry :: CalamariAccount => & [TokenRegistry :: Kma ,] , Ss58AddressFormatRegistry :: CentrifugeAccount => & [TokenRegistry :: Cfg ,] , Ss58AddressFormatRegistry :: CereAccount => & [TokenRegistry :: Cere ,] , Ss58AddressFormatRegistry :: CessAccount => & [TokenRegistry :: Cess ,] , Ss58AddressFormatRegistry :: CessTestnetAccount => & [TokenRegistry :: Tcess ,] , Ss58AddressFormatRegistry :: ChainflipAccount => & [TokenRegistry :: Flip ,] , Ss58AddressFormatRegistry :: ChainxAccount => & [TokenRegistry :: Pcx ,] , Ss58AddressFormatRegistry :: CloudwalkMainnetAccount => & [TokenRegistry :: Cwn ,] , Ss58AddressFormatRegistry :: CloverAccount => & [TokenRegistry :: Clv ,] , Ss58AddressFormatRegistry :: ComposableAccount => & [TokenRegistry :: Layr ,] , Ss58AddressFormatRegistry :: ContextfreeAccount => & [TokenRegistry :: Ctx ,] , Ss58AddressFormatRegistry :: CordAccount => & [TokenRegistry :: Dhi , TokenRegistry :: Way ,] , Ss58AddressFormatRegistry :: CrustAccount => & [TokenRegistry :: Cru ,] , Ss58AddressFormatRegistry :: CurioAccount => & [TokenRegistry :: Cgt ,] , Ss58AddressFormatRegistry :: DarkAccount => & [] , Ss58AddressFormatRegistry :: DarwiniaAccount => & [TokenRegistry :: Ring ,] , Ss58AddressFormatRegistry :: DatahighwayAccount => & [] , Ss58AddressFormatRegistry :: DentnetAccount => & [TokenRegistry :: Dentx ,] , Ss58AddressFormatRegistry :: DockPosMainnetAccount => & [TokenRegistry :: Dck ,] , Ss58AddressFormatRegistry :: DorafactoryPolkadotAccount => & [TokenRegistry :: Dora ,] , Ss58AddressFormatRegistry :: EdgewareAccount => & [TokenRegistry :: Edg ,] , Ss58AddressFormatRegistry :: EfinityAccount => & [TokenRegistry :: Efi ,] , Ss58AddressFormatRegistry :: EquilibriumAccount => & [TokenRegistry :: Eq ,] , Ss58AddressFormatRegistry :: EternalCivilizationAccount => & [TokenRegistry :: Ecc ,] , Ss58AddressFormatRegistry :: FragnovaAccount => & [TokenRegistry :: Nova ,] , Ss58AddressFormatRegistry :: FrequencyAccount => & [TokenRegistry :: Frqcy ,] , Ss58AddressFormatRegistry :: G1Account => & [TokenRegistry :: G1 ,] , Ss58AddressFormatRegistry :: GeekAccount => & [TokenRegistry :: Geek ,] , Ss58AddressFormatRegistry :: GenshiroAccount => & [TokenRegistry :: Gens , TokenRegistry :: Eqd , TokenRegistry :: Lpt0 ,] , Ss58AddressFormatRegistry :: GmAccount => & [TokenRegistry :: Fren , TokenRegistry :: Gm , TokenRegistry :: Gn ,] , Ss58AddressFormatRegistry :: GoldenGateAccount => & [TokenRegistry :: Ggx ,] , Ss58AddressFormatRegistry :: GoldenGateSydneyAccount => & [TokenRegistry :: Ggxt ,] , Ss58AddressFormatRegistry :: GoroAccount => & [TokenRegistry :: Goro ,] , Ss58AddressFormatRegistry :: HashedAccount => & [TokenRegistry :: Hash ,] , Ss58AddressFormatRegistry :: HeikoAccount => & [TokenRegistry :: Hko ,] , Ss58AddressFormatRegistry :: HumanodeAccount => & [TokenRegistry :: Hmnd ,] , Ss58AddressFormatRegistry :: HydradxAccount => & [TokenRegistry :: Hdx ,] , Ss58AddressFormatRegistry :: IbtidaAccount => & [TokenRegistry :: Ianml ,] , Ss58AddressFormatRegistry :: ImpactAccount => & [TokenRegistry :: Bsty ,] , Ss58AddressFormatRegistry :: IntegriteeAccount => & [TokenRegistry :: Teer ,] , Ss58AddressFormatRegistry :: IntegriteeIncognitoAccount => & [] , Ss58AddressFormatRegistry :: InterlayAccount => & [TokenRegistry :: Intr ,] , Ss58AddressFormatRegistry :: JoystreamAccount => & [TokenRegistry :: Joy ,] , Ss58AddressFormatRegistry :: JupiterAccount => & [TokenRegistry :: Jdot ,] , Ss58AddressFormatRegistry :: KabochaAccount => & [TokenRegistry :: Kab ,] , Ss58AddressFormatRegistry :: KapexAccount => & [TokenRegistry :: Kapex ,] , Ss58AddressFormatRegistry :: KarmachainAccount => & [TokenRegistry :: Kcoin ,] , Ss58AddressFormatRegistry :: KaruraAccount => & [TokenRegistry :: Kar ,] , Ss58AddressFormatRegistry :: KatalchainAccount => & [] , Ss58AddressFormatRegistry :: KiltAccount => & [TokenRegistry :: Kilt ,] , Ss58AddressFormatRegistry :: KintsugiAccount => & [TokenRegistry :: Kint ,] , Ss58AddressFormatRegistry :: KrestAccount => & [TokenRegistry :: Krest ,] , Ss58AddressFormatRegistry :: KriganAccount => & [TokenRegistry :: Krgn ,] , Ss58AddressFormatRegistry :: KulupuAccount => & [TokenRegistry :: Klp ,] , Ss58AddressFormatRegistry :: KusamaAccount => & [TokenRegistry :: Ksm ,] , Ss58AddressFormatRegistry :: LaminarAccount => & [TokenRegistry :: Lami ,] , Ss58AddressFormatRegistry :: LitentryAccount => & [TokenRegistry :: Lit ,] , Ss58AddressFormatRegistry :: LitmusAccount => & [TokenRegistry :: Lit ,] , Ss58AddressFormatRegistry :: LogionAccount => & [TokenRegistry :: Lgnt ,] , Ss58AddressFormatRegistry :: LuhnAccount => & [TokenRegistry :: Luhn ,] , Ss58AddressFormatRegistry :: MantaAccount => & [TokenRegistry :: Manta ,] , Ss58AddressFormatRegistry :: MathchainAccount => & [TokenRegistry :: Math ,] , Ss58AddressFormatRegistry :: MathchainTestnetAccount => & [TokenRegistry :: Math ,] , Ss58AddressFormatRegistry :: MetaquityNetworkAccount => & [TokenRegistry :: Mqty ,] , Ss58AddressFormatRegistry :: MoonbeamAccount => & [TokenRegistry :: Glmr ,] , Ss58AddressFormatRegistry :: MoonriverAccount => & [TokenRegistry :: Movr ,] , Ss58AddressFormatRegistry :: MoonsamaAccount => & [TokenRegistry :: Sama ,] , Ss58AddressFormatRegistry :: MythosAccount => & [TokenRegistry :: Myth ,] , Ss58AddressFormatRegistry :: NeatcoinAccount => & [TokenRegistry :: Neat ,] , Ss58AddressFormatRegistry :: NftmartAccount => & [TokenRegistry :: Nmt ,] , Ss58AddressFormatRegistry :: NodleAccount => & [TokenRegistry :: Nodl ,] , Ss58AddressFormatRegistry :: OakAccount => & [TokenRegistry :: Oak , TokenRegistry :: Tur ,] , Ss58AddressFormatRegistry :: OrigintrailParachainAccount => & [TokenRegistry :: Otp ,] , Ss58AddressFormatRegistry :: P3DAccount => & [TokenRegistry :: P3D ,] , Ss58AddressFormatRegistry :: P3DtAccount => & [TokenRegistry :: P3Dt ,] , Ss58AddressFormatRegistry :: ParallelAccount => & [TokenRegistry :: Para ,] , Ss58AddressFormatRegistry :: PeaqAccount => & [TokenRegistry :: Peaq ,] , Ss58AddressFormatRegistry :: PeerplaysAccount => & [TokenRegistry :: Ppy ,] , Ss58AddressFormatRegistry :: PendulumAccount => & [TokenRegistry :: Pen ,] , Ss58AddressFormatRegistry :: PhalaAccount => & [TokenRegistry :: Pha ,] , Ss58AddressFormatRegistry :: PicassoAccount => & [TokenRegistry :: Pica ,] , Ss58AddressFormatRegistry :: PioneerNetworkAccount => & [TokenRegistry :: Neer ,] , Ss58AddressFormatRegistry :: PolimecAccount => & [TokenRegistry :: Plmc ,] , Ss58AddressFormatRegistry :: PolkadexAccount => & [TokenRegistry :: Pdex ,] , Ss58AddressFormatRegistry :: PolkadexparachainAccount => & [TokenRegistry :: Pdex ,] , Ss58AddressFormatRegistry :: PolkadotAccount => & [TokenRegistry :: Dot ,] , Ss58AddressFormatRegistry :: PolkafoundryAccount => & [TokenRegistry :: Pkf ,] , Ss58AddressFormatRegistry :: PolkasmithAccount => & [TokenRegistry :: Pks ,] , Ss58AddressFormatRegistry :: PolymeshAccount => & [TokenRegistry :: Polyx ,] , Ss58AddressFormatRegistry :: PontemNetworkAccount => & [TokenRegistry :: Pont ,] , Ss58AddressFormatRegistry :: QuartzMainnetAccount => & [TokenRegistry :: Qtz ,] , Ss58AddressFormatRegistry :: Reserved46Account => & [] , Ss58AddressFormatRegistry :: Reserved47Account => & [] , Ss58AddressFormatRegistry :: ReynoldsAccount => & [TokenRegistry :: Rey ,] , Ss58AddressFormatRegistry :: RobonomicsAccount => & [TokenRegistry :: Xrt ,] , Ss58AddressFormatRegistry :: SapphireMainnetAccount => & [TokenRegistry :: Qtz ,] , Ss58AddressFormatRegistry :: SealsAccount => & [TokenRegistry :: Seal ,] , Ss58AddressFormatRegistry :: ShiftAccount => & [] , Ss58AddressFormatRegistry :: SocialNetworkAccount => & [TokenRegistry :: Net ,] , Ss58AddressFormatRegistry :: SocietalAccount => & [TokenRegistry :: Sctl ,] , Ss58AddressFormatRegistry :: SoraAccount => & [TokenRegistry :: Xor ,] , Ss58AddressFormatRegistry :: SoraDotParaAccount => & [TokenRegistry :: Xor ,] , Ss58AddressFormatRegistry :: SoraKusamaParaAccount => & [TokenRegistry :: Xor ,] , Ss58AddressFormatRegistry :: StafiAccount => & [TokenRegistry :: Fis ,] , Ss58AddressFormatRegistry :: SubsocialAccount => & [] , Ss58AddressFormatRegistry :: SubspaceAccount => & [TokenRegistry :: Ssc ,] , Ss58AddressFormatRegistry :: SubspaceTestnetAccount => & [TokenRegistry :: Tssc ,] , Ss58AddressFormatRegistry :: SubstrateAccount => & [] , Ss58AddressFormatRegistry :: SynesthesiaAccount => & [TokenRegistry :: Syn ,] , Ss58AddressFormatRegistry :: T3RnAccount => & [TokenRegistry :: Trn ,] , Ss58AddressFormatRegistry :: TangleAccount => & [TokenRegistry :: Tnt ,] , Ss58AddressFormatRegistry :: TernoaAccount => & [TokenRegistry :: Caps ,] , Ss58AddressFormatRegistry :: TidefiAccount => & [TokenRegistry :: Tdfy ,] , Ss58AddressFormatRegistry :: TinkerAccount => & [TokenRegistry :: Tnkr ,] , Ss58AddressFormatRegistry :: TotemAccount => & [TokenRegistry :: Totem ,] , Ss58AddressFormatRegistry :: UniartsAccount => & [TokenRegistry :: Uart , TokenRegistry :: Uink ,] , Ss58AddressFormatRegistry :: UniqueMainnetAccount => & [TokenRegistry :: Unq ,] , Ss58AddressFormatRegistry :: VaraAccount => & [TokenRegistry :: Vara ,] , Ss58AddressFormatRegistry :: VlnAccount => & [TokenRegistry :: Usdv ,] , Ss58AddressFormatRegistry :: WatrAccount => & [TokenRegistry :: Watr ,] , Ss58AddressFormatRegistry :: XxnetworkAccount => & [TokenRegistry :: Xx ,] , Ss58AddressFormatRegistry :: ZeitgeistAccount => & [TokenRegistry :: Ztg ,] , Ss58AddressFormatRegistry :: ZeroAccount => & [TokenRegistry :: Zero ,] , Ss58AddressFormatRegistry :: ZeroAlphavilleAccount => & [TokenRegistry :: Zero ,] , } } } impl Ss58AddressFormat { # [doc = r" Network/AddressType is reserved for future use."] pub fn is_reserved (& self) -> bool { self . prefix () > 16384 || matches ! (self . prefix () , 46u16 | 47u16) } # [doc = r" A custom format is one that is not already known."] pub fn is_custom (& self) -> bool { ! matches ! (self . prefix () , 0u16 ..= 58u16 | 63u16 ..= 63u16 | 65u16 ..= 69u16 | 71u16 ..= 73u16 | 77u16 ..= 78u16 | 81u16 ..= 81u16 | 88u16 ..= 90u16 | 92u16 ..= 93u16 | 98u16 ..= 101u16 | 105u16 ..= 105u16 | 110u16 ..= 110u16 | 113u16 ..= 113u16 | 117u16 ..= 117u16 | 126u16 ..= 126u16 | 128u16 ..= 129u16 | 131u16 ..= 131u16 | 136u16 ..= 137u16 | 172u16 ..= 172u16 | 252u16 ..= 252u16 | 255u16 ..= 255u16 | 268u16 ..= 268u16 | 420u16 ..= 420u16 | 440u16 ..= 440u16 | 666u16 ..= 666u16 | 777u16 ..= 777u16 | 789u16 ..= 789u16 | 995u16 ..= 995u16 | 1110u16 ..= 1110u16 | 1221u16 ..= 1222u16 | 1284u16 ..= 1285u16 | 1328u16 ..= 1328u16 | 1337u16 ..= 1337u16 | 1516u16 ..= 1516u16 | 1985u16 ..= 1985u16 | 2007u16 ..= 2007u16 | 2009u16 ..= 2009u16 | 2021u16 ..= 2021u16 | 2032u16 ..= 2032u16 | 2092u16 ..= 2092u16 | 2106u16 ..= 2106u16 | 2112u16 ..= 2112u16 | 2199u16 ..= 2199u16 | 2206u16 ..= 2207u16 | 2254u16 ..= 2254u16 | 3333u16 ..= 3333u16 | 4450u16 ..= 4450u16 | 5234u16 ..= 5234u16 | 5845u16 ..= 5845u16 | 6094u16 ..= 6094u16 | 7007u16 ..= 7007u16 | 7013u16 ..= 7013u16 | 7306u16 ..= 7306u16 | 7391u16 ..= 7391u16 | 8866u16 ..= 8866u16 | 8883u16 ..= 8883u16 | 8886u16 ..= 8886u16 | 9072u16 ..= 9072u16 | 9807u16 ..= 9807u16 | 9935u16 ..= 9935u16 | 10041u16 ..= 10041u16 | 11330u16 ..= 11331u16 | 11486u16 ..= 11486u16 | 11820u16 ..= 11820u16 | 12155u16 ..= 12155u16 | 12191u16 ..= 12191u16 | 13116u16 ..= 13116u16 | 14697u16 ..= 14697u16 | 29972u16 ..= 29972u16) } } # [doc = r" List of well-known tokens used on some network in the ecosystem."] # [non_exhaustive] # [derive (Clone , Copy , PartialEq , Eq , PartialOrd , Ord , Hash)] pub enum TokenRegistry { # [doc = "ACA token used on Acala"] Aca , # [doc = "AFT token used on Allfeat Network"] Aft , # [doc = "AIR token used on Altair"] Air , # [doc = "AJUN token used on Ajuna Network"] Ajun , # [doc = "AMPE token used on Amplitude chain"] Ampe , # [doc = "ANML token used on Anmol Network"] Anml , # [doc = "ARES token used on Ares Protocol"] Ares , # [doc = "ASTR token used on Astar Network"] Astr , # [doc = "AVT token used on Aventus Mainnet"] Avt , # [doc = "BAJU token used on Bajun Network"] Baju , # [doc = "BBB token used on Bitgreen"] Bbb , # [doc = "BNC token used on Bifrost"] Bnc , # [doc = "BSTY token used on Impact Protocol Network"] Bsty , # [doc = "BSX token used on Basilisk"] Bsx , # [doc = "CAPS token used on Ternoa"] Caps , # [doc = "CERE token used on Cere Network"] Cere , # [doc = "CESS token used on CESS"] Cess , # [doc = "CFG token used on Centrifuge Chain"] Cfg , # [doc = "CGT token used on Curio"] Cgt , # [doc = "CLV token used on Clover Finance"] Clv , # [doc = "CRU token used on Crust Network"] Cru , # [doc = "CTX token used on Automata ContextFree"] Ctx , # [doc = "CWN token used on CloudWalk Network Mainnet"] Cwn , # [doc = "DCK token used on Dock Mainnet"] Dck , # [doc = "DENTX token used on DENTNet"] Dentx , # [doc = "DHI token used on CORD Network"] Dhi , # [doc = "DICO token used on DICO"] Dico , # [doc = "DORA token used on Dorafactory Polkadot Network"] Dora , # [doc = "DOT token used on Polkadot Relay Chain"] Dot , # [doc = "ECC token used on Eternal Civilization"] Ecc , # [doc = "EDG token used on Edgeware"] Edg , # [doc = "EFI token used on Efinity"] Efi , # [doc = "EQ token used on Equilibrium Network"] Eq , # [doc = "EQD token used on Genshiro Network"] Eqd , # [doc = "FIS token used on Stafi"] Fis , # [doc = "FLIP token used on Chainflip"] Flip , # [doc = "FREN token used on GM"] Fren , # [doc = "FRQCY token used on Frequency"] Frqcy , # [doc = "G1 token used on Ğ1"] G1 , # [doc = "GEEK token used on GEEK Network"] Geek , # [doc = "GENS token used on Genshiro Network"] Gens , # [doc = "GGX token used on Golden Gate"] Ggx , # [doc = "GGXT token used on Golden Gate Sydney"] Ggxt , # [doc = "GLMR token used on Moonbeam"] Glmr , # [doc = "GM token used on GM"] Gm , # [doc = "GN token used on GM"] Gn , # [doc = "GORO token used on GORO Network"] Goro , # [doc = "HASH token used on Hashed Network"] Hash , # [doc = "HDX token used on HydraDX"] Hdx , # [doc = "HKO token used on Heiko"] Hko , # [doc = "HMND token used on Humanode Network"] Hmnd , # [doc = "IANML token used on Anmol Network Ibtida Canary network"] Ianml , # [doc = "ICY token used on ICE Network"] Icy , # [doc = "ICZ token used on SNOW: ICE Canary Network"] Icz , # [doc = "INTR token used on Interlay"] Intr , # [doc = "JOY token used on Joystream"] Joy , # [doc = "KAB token used on Kabocha"] Kab , # [doc = "KAPEX token used on Kapex"] Kapex , # [doc = "KAR token used on Karura"] Kar , # [doc = "KCOIN token used on Karmacoin"] Kcoin , # [doc = "KICO token used on KICO"] Kico , # [doc = "KILT token used on KILT Spiritnet"] Kilt , # [doc = "KINT token used on Kintsugi"] Kint , # [doc = "KLP token used on Kulupu"] Klp , # [doc = "KMA token used on Calamari: Manta Canary Network"] Kma , # [doc = "KREST token used on Krest Network"] Krest , # [doc = "KRGN token used on Krigan Network"] Krgn , # [doc = "KSM token used on Kusama Relay Chain"] Ksm , # [doc = "LAMI token used on Laminar"] Lami , # [doc = "LAYR token used on Composable Finance"] Layr , # [doc = "LGNT token used on logion network"] Lgnt , # [doc = "LIT token used on Litentry Network, Litmus Network"] Lit , # [doc = "LPT0 token used on Genshiro Network"] Lpt0 , # [doc = "LUHN token used on Luhn Network"] Luhn , # [doc = "MANTA token used on Manta network"] Manta , # [doc = "MATH token used on MathChain mainnet, MathChain testnet"] Math , # [doc = "MOVR token used on Moonriver"] Movr , # [doc = "MQTY token used on Metaquity Network"] Mqty , # [doc = "MYTH token used on Mythos"] Myth , # [doc = "NEAT token used on Neatcoin Mainnet"] Neat , # [doc = "NEER token used on Pioneer Network by Bit.Country"] Neer , # [doc = "NET token used on Social Network"] Net , # [doc = "NMT token used on NFTMart"] Nmt , # [doc = "NODL token used on Nodle Chain"] Nodl , # [doc = "NOVA token used on Fragnova Network"] Nova , # [doc = "OAK token used on OAK Network"] Oak , # [doc = "OTP token used on OriginTrail Parachain"] Otp , # [doc = "P3D token used on 3DP network"] P3D , # [doc = "P3Dt token used on 3DP test network"] P3Dt , # [doc = "PARA token used on Parallel"] Para , # [doc = "PCX token used on ChainX"] Pcx , # [doc = "PDEX token used on Polkadex Mainnet, Polkadex Parachain"] Pdex , # [doc = "PEAQ token used on Peaq Network"] Peaq , # [doc = "PEN token used on Pendulum chain"] Pen , # [doc = "PHA token used on Phala Network"] Pha , # [doc = "PICA token used on Picasso"] Pica , # [doc = "PKF token used on PolkaFoundry Network"] Pkf , # [doc = "PKS token used on PolkaSmith Canary Network"] Pks , # [doc = "PLMC token used on Polimec Protocol"] Plmc , # [doc = "POLYX token used on Polymesh"] Polyx , # [doc = "PONT token used on Pontem Network"] Pont , # [doc = "PPY token used on Peerplays"] Ppy , # [doc = "QTZ token used on QUARTZ by UNIQUE, Sapphire by Unique"] Qtz , # [doc = "REY token used on Laminar Reynolds Canary"] Rey , # [doc = "RING token used on Darwinia Network"] Ring , # [doc = "SAMA token used on Moonsama"] Sama , # [doc = "SCTL token used on Societal"] Sctl , # [doc = "SEAL token used on Seals Network"] Seal , # [doc = "SSC token used on Subspace"] Ssc , # [doc = "SYN token used on Synesthesia"] Syn , # [doc = "TAO token used on Bittensor"] Tao , # [doc = "TCESS token used on CESS Testnet"] Tcess , # [doc = "TDFY token used on Tidefi"] Tdfy , # [doc = "TEER token used on Integritee"] Teer , # [doc = "TNKR token used on Tinker"] Tnkr , # [doc = "TNT token used on Tangle Network"] Tnt , # [doc = "TOTEM token used on Totem"] Totem , # [doc = "TRN token used on t3rn"] Trn , # [doc = "TUR token used on OAK Network"] Tur , # [doc = "UART token used on UniArts Network"] Uart , # [doc = "UINK token used on UniArts Network"] Uink , # [doc = "UNQ token used on Unique Network"] Unq , # [doc = "USDv token used on Valiu Liquidity Network"] Usdv , # [doc = "VARA token used on Vara Network"] Vara , # [doc = "WATR token used on Watr Protocol"] Watr , # [doc = "WAY token used on CORD Network"] Way , # [doc = "XOR token used on SORA Network, SORA Polkadot Parachain, SORA Kusama Parachain"] Xor , # [doc = "XRT token used on Robonomics"] Xrt , # [doc = "XX token used on xx network"] Xx , # [doc = "ZERO token used on ZERO, ZERO Alphaville"] Zero , # [doc = "ZTG token used on Zeitgeist"] Ztg , # [doc = "jDOT token used on Jupiter"] Jdot , # [doc = "tSSC token used on Subspace testnet"] Tssc , } impl From < TokenRegistry > for Token { fn from (x : TokenRegistry) -> Self { match x { TokenRegistry :: Aca => Token { name : "ACA" , decimals : 12u8 } , TokenRegistry :: Aft => Token { name : "AFT" , decimals : 12u8 } , TokenRegistry :: Air => Token { name : "AIR" , decimals : 18u8 } , TokenRegistry :: Ajun => Token { name : "AJUN" , decimals : 12u8 } , TokenRegistry :: Ampe => Token { name : "AMPE" , decimals : 12u8 } , TokenRegistry :: Anml => Token { name : "ANML" , decimals : 18u8 } , TokenRegistry :: Ares => Token { name : "ARES" , decimals : 12u8 } , TokenRegistry :: Astr => Token { name : "ASTR" , decimals : 18u8 } , TokenRegistry :: Avt => Token { name : "AVT" , decimals : 18u8 } , TokenRegistry :: Baju => Token { name : "BAJU" , decimals : 12u8 } , TokenRegistry :: Bbb => Token { name : "BBB" , decimals : 18u8 } , TokenRegistry :: Bnc => Token { name : "BNC" , decimals : 12u8 } , TokenRegistry :: Bsty => Token { name : "BSTY" , decimals : 18u8 } , TokenRegistry :: Bsx => Token { name : "BSX" , decimals : 12u8 } , TokenRegistry :: Caps => Token { name : "CAPS" , decimals : 18u8 } , TokenRegistry :: Cere => Token { name : "CERE" , decimals : 10u8 } , TokenRegistry :: Cess => Token { name : "CESS" , decimals : 18u8 } , TokenRegistry :: Cfg => Token { name : "CFG" , decimals : 18u8 } , TokenRegistry :: Cgt => Token { name : "CGT" , decimals : 18u8 } , TokenRegistry :: Clv => Token { name : "CLV" , decimals : 18u8 } , TokenRegistry :: Cru => Token { name : "CRU" , decimals : 12u8 } , TokenRegistry :: Ctx => Token { name : "CTX" , decimals : 18u8 } , TokenRegistry :: Cwn => Token { name : "CWN" , decimals : 18u8 } , TokenRegistry :: Dck => Token { name : "DCK" , decimals : 6u8 } , TokenRegistry :: Dentx => Token { name : "DENTX" , decimals : 18u8 } , TokenRegistry :: Dhi => Token { name : "DHI" , decimals : 12u8 } , TokenRegistry :: Dico => Token { name : "DICO" , decimals : 14u8 } , TokenRegistry :: Dora => Token { name : "DORA" , decimals : 12u8 } , TokenRegistry :: Dot => Token { name : "DOT" , decimals : 10u8 } , TokenRegistry :: Ecc => Token { name : "ECC" , decimals : 12u8 } , TokenRegistry :: Edg => Token { name : "EDG" , decimals : 18u8 } , TokenRegistry :: Efi => Token { name : "EFI" , decimals : 18u8 } , TokenRegistry :: Eq => Token { name : "EQ" , decimals : 9u8 } , TokenRegistry :: Eqd => Token { name : "EQD" , decimals : 9u8 } , TokenRegistry :: Fis => Token { name : "FIS" , decimals : 12u8 } , TokenRegistry :: Flip => Token { name : "FLIP" , decimals : 18u8 } , TokenRegistry :: Fren => Token { name : "FREN" , decimals : 12u8 } , TokenRegistry :: Frqcy => Token { name : "FRQCY" , decimals : 8u8 } , TokenRegistry :: G1 => Token { name : "G1" , decimals : 2u8 } , TokenRegistry :: Geek => Token { name : "GEEK" , decimals : 18u8 } , TokenRegistry :: Gens => Token { name : "GENS" , decimals : 9u8 } , TokenRegistry :: Ggx => Token { name : "GGX" , decimals : 18u8 } , TokenRegistry :: Ggxt => Token { name : "GGXT" , decimals : 18u8 } , TokenRegistry :: Glmr => Token { name : "GLMR" , decimals : 18u8 } , TokenRegistry :: Gm => Token { name : "GM" , decimals : 0u8 } , TokenRegistry :: Gn => Token { name : "GN" , decimals : 0u8 } , TokenRegistry :: Goro => Token { name : "GORO" , decimals : 9u8 } , TokenRegistry :: Hash => Token { name : "HASH" , decimals : 18u8 } , TokenRegistry :: Hdx => Token { name : "HDX" , decimals : 12u8 } , TokenRegistry :: Hko => Token { name : "HKO" , decimals : 12u8 } , TokenRegistry :: Hmnd => Token { name : "HMND" , decimals : 18u8 } , TokenRegistry :: Ianml => Token { name : "IANML" , decimals : 18u8 } , TokenRegistry :: Icy => Token { name : "ICY" , decimals : 18u8 } , TokenRegistry :: Icz => Token { name : "ICZ" , decimals : 18u8 } , TokenRegistry :: Intr => Token { name : "INTR" , decimals : 10u8 } , TokenRegistry :: Joy => Token { name : "JOY" , decimals : 10u8 } , TokenRegistry :: Kab => Token { name : "KAB" , decimals : 12u8 } , TokenRegistry :: Kapex => Token { name : "KAPEX" , decimals : 12u8 } , TokenRegistry :: Kar => Token { name : "KAR" , decimals : 12u8 } , TokenRegistry :: Kcoin => Token { name : "KCOIN" , decimals : 6u8 } , TokenRegistry :: Kico => Token { name : "KICO" , decimals : 14u8 } , TokenRegistry :: Kilt => Token { name : "KILT" , decimals : 15u8 } , TokenRegistry :: Kint => Token { name : "KINT" , decimals : 12u8 } , TokenRegistry :: Klp => Token { name : "KLP" , decimals : 12u8 } , TokenRegistry :: Kma => Token { name : "KMA" , decimals : 12u8 } , TokenRegistry :: Krest => Token { name : "KREST" , decimals : 18u8 } , TokenRegistry :: Krgn => Token { name : "KRGN" , decimals : 9u8 } , TokenRegistry :: Ksm => Token { name : "KSM" , decimals : 12u8 } , TokenRegistry :: Lami => Token { name : "LAMI" , decimals : 18u8 } , TokenRegistry :: Layr => Token { name : "LAYR" , decimals : 12u8 } , TokenRegistry :: Lgnt => Token { name : "LGNT" , decimals : 18u8 } , TokenRegistry :: Lit => Token { name : "LIT" , decimals : 12u8 } , TokenRegistry :: Lpt0 => Token { name : "LPT0" , decimals : 9u8 } , TokenRegistry :: Luhn => Token { name : "LUHN" , decimals : 18u8 } , TokenRegistry :: Manta => Token { name : "MANTA" , decimals : 18u8 } , TokenRegistry :: Math => Token { name : "MATH" , decimals : 18u8 } , TokenRegistry :: Movr => Token { name : "MOVR" , decimals : 18u8 } , TokenRegistry :: Mqty => Token { name : "MQTY" , decimals : 18u8 } , TokenRegistry :: Myth => Token { name : "MYTH" , decimals : 18u8 } , TokenRegistry :: Neat => Token { name : "NEAT" , decimals : 12u8 } , TokenRegistry :: Neer => Token { name : "NEER" , decimals : 18u8 } , TokenRegistry :: Net => Token { name : "NET" , decimals : 18u8 } , TokenRegistry :: Nmt => Token { name : "NMT" , decimals : 12u8 } , TokenRegistry :: Nodl => Token { name : "NODL" , decimals : 11u8 } , TokenRegistry :: Nova => Token { name : "NOVA" , decimals : 12u8 } , TokenRegistry :: Oak => Token { name : "OAK" , decimals : 10u8 } , TokenRegistry :: Otp => Token { name : "OTP" , decimals : 12u8 } , TokenRegistry :: P3D => Token { name : "P3D" , decimals : 12u8 } , TokenRegistry :: P3Dt => Token { name : "P3Dt" , decimals : 12u8 } , TokenRegistry :: Para => Token { name : "PARA" , decimals : 12u8 } , TokenRegistry :: Pcx => Token { name : "PCX" , decimals : 8u8 } , TokenRegistry :: Pdex => Token { name : "PDEX" , decimals : 12u8 } , TokenRegistry :: Peaq => Token { name : "PEAQ" , decimals : 18u8 } , TokenRegistry :: Pen => Token { name : "PEN" , decimals : 12u8 } , TokenRegistry :: Pha => Token { name : "PHA" , decimals : 12u8 } , TokenRegistry :: Pica => Token { name : "PICA" , decimals : 12u8 } , TokenRegistry :: Pkf => Token { name : "PKF" , decimals : 18u8 } , TokenRegistry :: Pks => Token { name : "PKS" , decimals : 18u8 } , TokenRegistry :: Plmc => Token { name : "PLMC" , decimals : 10u8 } , TokenRegistry :: Polyx => Token { name : "POLYX" , decimals : 6u8 } , TokenRegistry :: Pont => Token { name : "PONT" , decimals : 10u8 } , TokenRegistry :: Ppy => Token { name : "PPY" , decimals : 18u8 } , TokenRegistry :: Qtz => Token { name : "QTZ" , decimals : 18u8 } , TokenRegistry :: Rey => Token { name : "REY" , decimals : 18u8 } , TokenRegistry :: Ring => Token { name : "RING" , decimals : 18u8 } , TokenRegistry :: Sama => Token { name : "SAMA" , decimals : 18u8 } , TokenRegistry :: Sctl => Token { name : "SCTL" , decimals : 12u8 } , TokenRegistry :: Seal => Token { name : "SEAL" , decimals : 9u8 } , TokenRegistry :: Ssc => Token { name : "SSC" , decimals : 18u8 } , TokenRegistry :: Syn => Token { name : "SYN" , decimals : 12u8 } , TokenRegistry :: Tao => Token { name : "TAO" , decimals : 9u8 } , TokenRegistry :: Tcess => Token { name : "TCESS" , decimals : 18u8 } , TokenRegistry :: Tdfy => Token { name : "TDFY" , decimals : 12u8 } , TokenRegistry :: Teer => Token { name : "TEER" , decimals : 12u8 } , TokenRegistry :: Tnkr => Token { name : "TNKR" , decimals : 12u8 } , TokenRegistry :: Tnt => Token { name : "TNT" , decimals : 18u8 } , TokenRegistry :: Totem => Token { name : "TOTEM" , decimals : 0u8 } , TokenRegistry :: Trn => Token { name : "TRN" , decimals : 12u8 } , TokenRegistry :: Tur => Token { name : "TUR" , decimals : 10u8 } , TokenRegistry :: Uart => Token { name : "UART" , decimals : 12u8 } , TokenRegistry :: Uink => Token { name : "UINK" , decimals : 12u8 } , TokenRegistry :: Unq => Token { name : "UNQ" , decimals : 18u8 } , TokenRegistry :: Usdv => Token { name : "USDv" , decimals : 15u8 } , TokenRegistry :: Vara => Token { name : "VARA" , decimals : 12u8 } , TokenRegistry :: Watr => Token { name : "WATR" , decimals : 18u8 } , TokenRegistry :: Way => Token { name : "WAY" , decimals : 12u8 } , TokenRegistry :: Xor => Token { name : "XOR" , decimals : 18u8 } , TokenRegistry :: Xrt => Token { name : "XRT" , decimals : 9u8 } , TokenRegistry :: Xx => Token { name : "XX" , decimals : 9u8 } , TokenRegistry :: Zero => Token { name : "ZERO" , decimals : 18u8 } , TokenRegistry :: Ztg => Token { name : "ZTG" , decimals : 10u8 } , TokenRegistry :: Jdot => Token { name : "jDOT" , decimals : 10u8 } , TokenRegistry :: Tssc => Token { name : "tSSC" , decimals : 18u8 } , } } }
\ No newline at end of file
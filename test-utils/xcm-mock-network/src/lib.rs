@@ -0,0 +1,241 @@
+// This file is part of RegionX.
+//
+// RegionX is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// RegionX is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with RegionX.  If not, see <https://www.gnu.org/licenses/>.
+
+//! A network made of a relay chain and the parachains provided by the tests, used for testing the
+//! XCM programs of the pallets end to end.
+//!
+//! Each chain runs in its own externalities. The messages sent by a chain are queued and executed
+//! on their destination once the closure passed to `execute_with` returns, the same way
+//! `xcm-simulator` does it. The queue is drained the way the messages would be processed with all
+//! chains producing blocks in lockstep. Horizontal messages are executed first, in the order in
+//! which they were sent. Upward messages follow, and downward messages come last: a message routed
+//! through the relay chain only reaches a parachain a block after a horizontal message sent at the
+//! same time.
+
+pub mod relay;
+
+use codec::Encode;
+use core::{cell::RefCell, marker::PhantomData};
+use sp_core::{ConstU128, Get};
+use sp_io::hashing::blake2_256;
+use std::collections::BTreeMap;
+use xcm::latest::prelude::*;
+use xcm_executor::XcmExecutor;
+
+/// A chain of the network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chain {
+	Relay,
+	Para(u32),
+}
+
+/// Executes a message coming from the given origin on a parachain.
+pub type ExecuteMessage = fn(MultiLocation, Xcm<()>) -> Outcome;
+
+/// Executes `message` with the `XcmExecutor` of the chain configured by `Config`.
+pub fn execute_xcm<Config: xcm_executor::Config>(
+	origin: MultiLocation,
+	message: Xcm<()>,
+) -> Outcome {
+	let hash = message.using_encoded(blake2_256);
+	XcmExecutor::<Config>::execute_xcm(origin, message.into(), hash, Weight::MAX)
+}
+
+/// A parachain of the network.
+pub struct Parachain {
+	/// The id of the parachain.
+	pub id: u32,
+	/// The initial state of the parachain.
+	pub ext: sp_io::TestExternalities,
+	/// Executes the messages sent to the parachain.
+	pub execute_xcm: ExecuteMessage,
+}
+
+thread_local! {
+	static RELAY_EXT: RefCell<sp_io::TestExternalities> = RefCell::new(relay::new_ext(&[]));
+	/// The state of each parachain, together with the way it executes messages.
+	static PARACHAINS: RefCell<BTreeMap<u32, (RefCell<sp_io::TestExternalities>, ExecuteMessage)>> =
+		const { RefCell::new(BTreeMap::new()) };
+	/// The messages which are yet to be executed, as `(sender, destination, message)`.
+	static MESSAGE_QUEUE: RefCell<Vec<(Chain, Chain, Xcm<()>)>> = const { RefCell::new(Vec::new()) };
+	/// The outcome of each executed message, as `(destination, outcome)`.
+	static OUTCOMES: RefCell<Vec<(Chain, Outcome)>> = const { RefCell::new(Vec::new()) };
+	/// Every message sent through the routers, as `(sender, destination, message)`.
+	static SENT_MESSAGES: RefCell<Vec<(Chain, Chain, Xcm<()>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sets up a fresh network made of the relay chain and `parachains`, and drops all pending
+/// messages.
+///
+/// The parachains in `reserves` get `relay::PARACHAIN_RESERVE` in their sovereign account on the
+/// relay chain.
+pub fn reset(parachains: Vec<Parachain>, reserves: &[u32]) {
+	RELAY_EXT.with(|ext| *ext.borrow_mut() = relay::new_ext(reserves));
+	PARACHAINS.with(|chains| {
+		*chains.borrow_mut() = parachains
+			.into_iter()
+			.map(|para| (para.id, (RefCell::new(para.ext), para.execute_xcm)))
+			.collect()
+	});
+	MESSAGE_QUEUE.with(|queue| queue.borrow_mut().clear());
+	OUTCOMES.with(|outcomes| outcomes.borrow_mut().clear());
+	SENT_MESSAGES.with(|messages| messages.borrow_mut().clear());
+}
+
+/// Returns the outcomes of the messages executed since the last call.
+pub fn take_outcomes() -> Vec<(Chain, Outcome)> {
+	OUTCOMES.with(|outcomes| outcomes.take())
+}
+
+/// Returns the messages sent since the last call, as `(sender, destination, message)`.
+pub fn take_sent_messages() -> Vec<(Chain, Chain, Xcm<()>)> {
+	SENT_MESSAGES.with(|messages| messages.take())
+}
+
+/// The relay chain.
+pub struct Relay;
+impl Relay {
+	/// Executes `f` on the relay chain and delivers the messages sent in the meantime.
+	pub fn execute_with<R>(f: impl FnOnce() -> R) -> R {
+		let result = RELAY_EXT.with(|ext| ext.borrow_mut().execute_with(f));
+		deliver_messages();
+		result
+	}
+}
+
+/// The parachain with id `Id`.
+pub struct Para<Id>(PhantomData<Id>);
+impl<Id: Get<u32>> Para<Id> {
+	/// Executes `f` on the parachain and delivers the messages sent in the meantime.
+	pub fn execute_with<R>(f: impl FnOnce() -> R) -> R {
+		let result = PARACHAINS.with(|chains| {
+			let chains = chains.borrow();
+			let (ext, _) = chains.get(&Id::get()).expect("the parachain is part of the network");
+			let result = ext.borrow_mut().execute_with(f);
+			result
+		});
+		deliver_messages();
+		result
+	}
+}
+
+/// Returns whether the parachain with the given id is part of the network.
+fn is_parachain(id: u32) -> bool {
+	PARACHAINS.with(|chains| chains.borrow().contains_key(&id))
+}
+
+/// Returns the position of the next message to execute: horizontal messages go first, followed
+/// by upward and downward ones.
+fn next_message(queue: &[(Chain, Chain, Xcm<()>)]) -> Option<usize> {
+	queue
+		.iter()
+		.position(|(sender, destination, _)| {
+			*sender != Chain::Relay && *destination != Chain::Relay
+		})
+		.or_else(|| queue.iter().position(|(_, destination, _)| *destination == Chain::Relay))
+		.or(if queue.is_empty() { None } else { Some(0) })
+}
+
+/// Executes the queued messages on their destination, until no more messages are sent.
+fn deliver_messages() {
+	while let Some((sender, destination, message)) = MESSAGE_QUEUE.with(|queue| {
+		let mut queue = queue.borrow_mut();
+		next_message(&queue).map(|index| queue.remove(index))
+	}) {
+		let origin = match (sender, destination) {
+			(Chain::Relay, _) => MultiLocation::parent(),
+			(Chain::Para(id), Chain::Relay) => MultiLocation::new(0, X1(Parachain(id))),
+			(Chain::Para(id), Chain::Para(_)) => MultiLocation::new(1, X1(Parachain(id))),
+		};
+		let outcome = match destination {
+			Chain::Relay => RELAY_EXT.with(|ext| {
+				ext.borrow_mut()
+					.execute_with(|| execute_xcm::<relay::XcmConfig>(origin, message))
+			}),
+			Chain::Para(id) => PARACHAINS.with(|chains| {
+				let chains = chains.borrow();
+				let (ext, execute) =
+					chains.get(&id).expect("the routers only accept messages for the network");
+				let outcome = ext.borrow_mut().execute_with(|| execute(origin, message));
+				outcome
+			}),
+		};
+		OUTCOMES.with(|outcomes| outcomes.borrow_mut().push((destination, outcome)));
+	}
+}
+
+/// Queues `message` for delivery and returns its hash.
+fn enqueue(sender: Chain, destination: Chain, message: Xcm<()>) -> XcmHash {
+	let hash = message.using_encoded(blake2_256);
+	SENT_MESSAGES
+		.with(|messages| messages.borrow_mut().push((sender, destination, message.clone())));
+	MESSAGE_QUEUE.with(|queue| queue.borrow_mut().push((sender, destination, message)));
+	hash
+}
+
+/// Routes the messages sent by the parachain with id `Sender` to the relay chain and to its
+/// siblings in the network.
+///
+/// Each message is charged a delivery fee of `DeliveryFee` relay chain tokens.
+pub struct ParachainXcmRouter<Sender, DeliveryFee = ConstU128<0>>(
+	PhantomData<(Sender, DeliveryFee)>,
+);
+impl<Sender: Get<u32>, DeliveryFee: Get<u128>> SendXcm for ParachainXcmRouter<Sender, DeliveryFee> {
+	type Ticket = (Chain, Xcm<()>);
+
+	fn validate(
+		destination: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let chain = match destination.as_ref().ok_or(SendError::MissingArgument)? {
+			MultiLocation { parents: 1, interior: Here } => Chain::Relay,
+			MultiLocation { parents: 1, interior: X1(Parachain(id)) } if is_parachain(*id) =>
+				Chain::Para(*id),
+			_ => return Err(SendError::NotApplicable),
+		};
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		let price = match DeliveryFee::get() {
+			0 => MultiAssets::new(),
+			fee => (Parent, fee).into(),
+		};
+		Ok(((chain, message), price))
+	}
+
+	fn deliver((destination, message): Self::Ticket) -> Result<XcmHash, SendError> {
+		Ok(enqueue(Chain::Para(Sender::get()), destination, message))
+	}
+}
+
+/// Routes the messages sent by the relay chain to the parachains of the network.
+pub struct RelayXcmRouter;
+impl SendXcm for RelayXcmRouter {
+	type Ticket = (u32, Xcm<()>);
+
+	fn validate(
+		destination: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let para_id = match destination.as_ref().ok_or(SendError::MissingArgument)? {
+			MultiLocation { parents: 0, interior: X1(Parachain(id)) } if is_parachain(*id) => *id,
+			_ => return Err(SendError::NotApplicable),
+		};
+		let message = message.take().ok_or(SendError::MissingArgument)?;
+		Ok(((para_id, message), MultiAssets::new()))
+	}
+
+	fn deliver((para_id, message): Self::Ticket) -> Result<XcmHash, SendError> {
+		Ok(enqueue(Chain::Relay, Chain::Para(para_id), message))
+	}
+}